@@ -41,8 +41,9 @@ impl Resemblance {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum Scheme {
+    #[default]
     Additive,       // Current weighted average approach
     Multiplicative, // All dimensions must contribute (product-based)
     Minimum,        // Limited by weakest dimension
@@ -51,13 +52,7 @@ pub enum Scheme {
     Harmonic,       // Harmonic mean of dimensions
 }
 
-impl Default for Scheme {
-    fn default() -> Self {
-        Scheme::Additive
-    }
-}
-
-pub trait Resembler<Query, Candidate, Error>: Debug + Send + Sync {
+pub trait Resembler<Query: ?Sized, Candidate: ?Sized, Error>: Debug + Send + Sync {
     fn resemblance(&mut self, query: &Query, candidate: &Candidate) -> Result<Resemblance, Error>;
 }
 
@@ -209,6 +204,17 @@ where
     }
 }
 
+impl<'assessor, Query, Candidate, Error> Default for Assessor<'assessor, Query, Candidate, Error>
+where
+    Query: Clone + Debug,
+    Candidate: Clone + Debug,
+    Error: Clone + Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'assessor, Query, Candidate, Error> Resembler<Query, Candidate, Error> for Assessor<'assessor, Query, Candidate, Error>
 where
     Query: Clone + Debug,
@@ -0,0 +1,98 @@
+use core::ops::Range;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operation {
+    Equal,
+    Insert,
+    Delete,
+    Replace,
+}
+
+/// One step of an edit script, covering `old` in the original and `new` in the revision.
+///
+/// Inserts have an empty `old` range positioned where the items go, deletes an empty `new` range.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Edit {
+    pub operation: Operation,
+    pub old: Range<usize>,
+    pub new: Range<usize>,
+}
+
+impl Edit {
+    pub fn new(operation: Operation, old: Range<usize>, new: Range<usize>) -> Self {
+        Self { operation, old, new }
+    }
+
+    pub fn is_equal(&self) -> bool {
+        self.operation == Operation::Equal
+    }
+}
+
+/// Appends an edit, merging it into the previous one when both are contiguous and alike.
+pub(crate) fn push(edits: &mut Vec<Edit>, edit: Edit) {
+    if edit.old.is_empty() && edit.new.is_empty() {
+        return;
+    }
+
+    if let Some(last) = edits.last_mut() {
+        if last.operation == edit.operation && last.old.end == edit.old.start && last.new.end == edit.new.start {
+            last.old.end = edit.old.end;
+            last.new.end = edit.new.end;
+            return;
+        }
+    }
+
+    edits.push(edit);
+}
+
+/// Pushes the change needed to turn `old` into `new` when no items in between match.
+pub(crate) fn gap(edits: &mut Vec<Edit>, old: Range<usize>, new: Range<usize>) {
+    let operation = match (old.is_empty(), new.is_empty()) {
+        (true, true) => return,
+        (true, false) => Operation::Insert,
+        (false, true) => Operation::Delete,
+        (false, false) => Operation::Replace,
+    };
+
+    push(edits, Edit::new(operation, old, new));
+}
+
+/// Builds an edit script from matched `(old, new)` index pairs given in ascending order.
+pub(crate) fn script(matches: &[(usize, usize)], old: usize, new: usize) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    let (mut position_old, mut position_new) = (0, 0);
+
+    for &(index_old, index_new) in matches {
+        gap(&mut edits, position_old..index_old, position_new..index_new);
+        push(&mut edits, Edit::new(Operation::Equal, index_old..index_old + 1, index_new..index_new + 1));
+        position_old = index_old + 1;
+        position_new = index_new + 1;
+    }
+
+    gap(&mut edits, position_old..old, position_new..new);
+    edits
+}
+
+/// Matches the shared prefix and suffix of two ranges and returns the ranges left between them.
+pub(crate) fn trim<T: Eq>(
+    old: &[T],
+    mut range_old: Range<usize>,
+    new: &[T],
+    mut range_new: Range<usize>,
+    matches: &mut Vec<(usize, usize)>,
+    suffix: &mut Vec<(usize, usize)>,
+) -> (Range<usize>, Range<usize>) {
+    while !range_old.is_empty() && !range_new.is_empty() && old[range_old.start] == new[range_new.start] {
+        matches.push((range_old.start, range_new.start));
+        range_old.start += 1;
+        range_new.start += 1;
+    }
+
+    while !range_old.is_empty() && !range_new.is_empty() && old[range_old.end - 1] == new[range_new.end - 1] {
+        range_old.end -= 1;
+        range_new.end -= 1;
+        suffix.push((range_old.end, range_new.end));
+    }
+
+    (range_old, range_new)
+}
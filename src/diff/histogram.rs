@@ -0,0 +1,80 @@
+use {
+    core::{hash::Hash, ops::Range},
+    hashish::HashMap,
+    crate::diff::{
+        edit::{script, trim, Edit},
+        myers::conquer,
+    },
+};
+
+/// Occurrence count above which an item is too common to anchor on.
+const CHAIN: usize = 64;
+
+/// Histogram diff: splits around the longest common region containing the rarest shared item.
+pub fn histogram<T: Eq + Hash>(old: &[T], new: &[T]) -> Vec<Edit> {
+    let mut matches = Vec::new();
+    split(old, 0..old.len(), new, 0..new.len(), &mut matches);
+    script(&matches, old.len(), new.len())
+}
+
+fn split<T: Eq + Hash>(old: &[T], range_old: Range<usize>, new: &[T], range_new: Range<usize>, matches: &mut Vec<(usize, usize)>) {
+    let mut suffix = Vec::new();
+    let (range_old, range_new) = trim(old, range_old, new, range_new, matches, &mut suffix);
+
+    if !range_old.is_empty() && !range_new.is_empty() {
+        match region(old, range_old.clone(), new, range_new.clone()) {
+            Some((start_old, start_new, length)) => {
+                split(old, range_old.start..start_old, new, range_new.start..start_new, matches);
+                matches.extend((0..length).map(|offset| (start_old + offset, start_new + offset)));
+                split(old, start_old + length..range_old.end, new, start_new + length..range_new.end, matches);
+            }
+            None => conquer(old, range_old, new, range_new, matches),
+        }
+    }
+
+    matches.extend(suffix.into_iter().rev());
+}
+
+/// The longest common run around the least frequent item of `old` that also occurs in `new`.
+fn region<T: Eq + Hash>(old: &[T], range_old: Range<usize>, new: &[T], range_new: Range<usize>) -> Option<(usize, usize, usize)> {
+    let mut occurrences: HashMap<&T, Vec<usize>> = HashMap::new();
+    for index in range_old.clone() {
+        occurrences.entry(&old[index]).or_default().push(index);
+    }
+
+    let mut best: Option<(usize, usize, usize, usize)> = None;
+
+    for index_new in range_new.clone() {
+        let positions = match occurrences.get(&new[index_new]) {
+            Some(positions) if positions.len() <= CHAIN => positions,
+            _ => continue,
+        };
+        if best.is_some_and(|(count, ..)| positions.len() > count) {
+            continue;
+        }
+
+        for &index_old in positions {
+            let mut start_old = index_old;
+            let mut start_new = index_new;
+            while start_old > range_old.start && start_new > range_new.start && old[start_old - 1] == new[start_new - 1] {
+                start_old -= 1;
+                start_new -= 1;
+            }
+
+            let mut length = index_old - start_old + 1;
+            while start_old + length < range_old.end && start_new + length < range_new.end && old[start_old + length] == new[start_new + length] {
+                length += 1;
+            }
+
+            let better = match best {
+                None => true,
+                Some((count, .., best_length)) => positions.len() < count || length > best_length,
+            };
+            if better {
+                best = Some((positions.len(), start_old, start_new, length));
+            }
+        }
+    }
+
+    best.map(|(_, start_old, start_new, length)| (start_old, start_new, length))
+}
@@ -0,0 +1,145 @@
+use {
+    core::{hash::Hash, ops::Range},
+    crate::diff::{
+        edit::Edit,
+        Algorithm,
+    },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Region<T> {
+    Resolved(Vec<T>),
+    /// Both sides changed `range` of the base differently.
+    Conflict {
+        range: Range<usize>,
+        base: Vec<T>,
+        ours: Vec<T>,
+        theirs: Vec<T>,
+    },
+}
+
+/// Outcome of a three-way merge, as resolved and conflicting regions in base order
+#[derive(Clone, Debug, PartialEq)]
+pub struct Merge<T> {
+    pub regions: Vec<Region<T>>,
+}
+
+/// Position in a side that corresponds to the start of `base` position `at`.
+fn lower(edits: &[Edit], at: usize, fallback: usize) -> usize {
+    edits.iter()
+        .find(|edit| edit.old.end > at || edit.old.start == at)
+        .map_or(fallback, |edit| {
+            if edit.is_equal() { edit.new.start + (at - edit.old.start) } else { edit.new.start }
+        })
+}
+
+/// Position in a side that corresponds to the end of `base` position `at`.
+fn upper(edits: &[Edit], at: usize) -> usize {
+    edits.iter()
+        .rev()
+        .find(|edit| edit.old.start < at || edit.old.end == at)
+        .map_or(0, |edit| {
+            if edit.is_equal() { edit.new.start + (at - edit.old.start) } else { edit.new.end }
+        })
+}
+
+impl<T: Clone + PartialEq> Merge<T> {
+    /// Merges two scripts made against the same base, `ours` and `theirs` being their revisions.
+    pub fn scripts(base: &[T], ours: &[T], ours_edits: &[Edit], theirs: &[T], theirs_edits: &[Edit]) -> Self {
+        let mut changes: Vec<(Range<usize>, bool)> = ours_edits.iter()
+            .filter(|edit| !edit.is_equal())
+            .map(|edit| (edit.old.clone(), true))
+            .chain(theirs_edits.iter().filter(|edit| !edit.is_equal()).map(|edit| (edit.old.clone(), false)))
+            .collect();
+        changes.sort_by_key(|(range, _)| (range.start, range.end));
+
+        // Changes that merely touch stay apart unless one is an insertion, whose order against
+        // the other change would be ambiguous.
+        let mut clusters: Vec<(Range<usize>, bool, bool)> = Vec::new();
+        for (range, mine) in changes {
+            match clusters.last_mut() {
+                Some((cluster, in_ours, in_theirs))
+                    if range.start < cluster.end || (range.start == cluster.end && (range.start == range.end || cluster.start == cluster.end)) =>
+                {
+                    cluster.end = cluster.end.max(range.end);
+                    *in_ours |= mine;
+                    *in_theirs |= !mine;
+                }
+                _ => clusters.push((range, mine, !mine)),
+            }
+        }
+
+        let mut merge = Merge { regions: Vec::new() };
+        let mut position = 0;
+
+        for (range, in_ours, in_theirs) in clusters {
+            merge.resolve(&base[position..range.start]);
+
+            let side = |items: &[T], edits: &[Edit], changed: bool| {
+                if changed {
+                    items[lower(edits, range.start, items.len())..upper(edits, range.end)].to_vec()
+                } else {
+                    base[range.clone()].to_vec()
+                }
+            };
+            let ours_items = side(ours, ours_edits, in_ours);
+            let theirs_items = side(theirs, theirs_edits, in_theirs);
+
+            if !in_theirs || ours_items == theirs_items {
+                merge.resolve(&ours_items);
+            } else if !in_ours {
+                merge.resolve(&theirs_items);
+            } else {
+                merge.regions.push(Region::Conflict {
+                    range: range.clone(),
+                    base: base[range.clone()].to_vec(),
+                    ours: ours_items,
+                    theirs: theirs_items,
+                });
+            }
+
+            position = range.end;
+        }
+
+        merge.resolve(&base[position..]);
+        merge
+    }
+
+    fn resolve(&mut self, items: &[T]) {
+        if items.is_empty() {
+            return;
+        }
+        match self.regions.last_mut() {
+            Some(Region::Resolved(resolved)) => resolved.extend_from_slice(items),
+            _ => self.regions.push(Region::Resolved(items.to_vec())),
+        }
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.regions.iter().all(|region| matches!(region, Region::Resolved(_)))
+    }
+
+    pub fn conflicts(&self) -> impl Iterator<Item = &Region<T>> {
+        self.regions.iter().filter(|region| matches!(region, Region::Conflict { .. }))
+    }
+
+    /// The merged sequence, if no region conflicts.
+    pub fn resolved(&self) -> Option<Vec<T>> {
+        let mut merged = Vec::new();
+        for region in &self.regions {
+            match region {
+                Region::Resolved(items) => merged.extend_from_slice(items),
+                Region::Conflict { .. } => return None,
+            }
+        }
+        Some(merged)
+    }
+}
+
+impl<T: Clone + Eq + Hash> Merge<T> {
+    pub fn new(base: &[T], ours: &[T], theirs: &[T], algorithm: Algorithm) -> Self {
+        let ours_edits = algorithm.diff(base, ours);
+        let theirs_edits = algorithm.diff(base, theirs);
+        Self::scripts(base, ours, &ours_edits, theirs, &theirs_edits)
+    }
+}
@@ -0,0 +1,39 @@
+pub mod edit;
+pub mod histogram;
+pub mod merge;
+pub mod myers;
+pub mod patch;
+pub mod patience;
+pub mod render;
+pub mod text;
+pub mod tree;
+
+pub use edit::*;
+pub use histogram::*;
+pub use merge::*;
+pub use myers::*;
+pub use patch::*;
+pub use patience::*;
+pub use render::*;
+pub use text::*;
+pub use tree::*;
+
+use core::hash::Hash;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Algorithm {
+    #[default]
+    Myers,
+    Patience,
+    Histogram,
+}
+
+impl Algorithm {
+    pub fn diff<T: Eq + Hash>(&self, old: &[T], new: &[T]) -> Vec<Edit> {
+        match self {
+            Algorithm::Myers => myers(old, new),
+            Algorithm::Patience => patience(old, new),
+            Algorithm::Histogram => histogram(old, new),
+        }
+    }
+}
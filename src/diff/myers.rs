@@ -0,0 +1,130 @@
+use {
+    core::ops::{Index, IndexMut, Range},
+    crate::diff::edit::{script, trim, Edit},
+};
+
+/// Diagonal-indexed furthest-reaching vector, `k` ranging over `-offset..=offset`.
+struct Frontier {
+    offset: isize,
+    values: Vec<usize>,
+}
+
+impl Frontier {
+    fn new(depth: usize) -> Self {
+        let offset = depth as isize + 1;
+        Self { offset, values: vec![0; 2 * depth + 3] }
+    }
+}
+
+impl Index<isize> for Frontier {
+    type Output = usize;
+
+    fn index(&self, k: isize) -> &usize {
+        &self.values[(k + self.offset) as usize]
+    }
+}
+
+impl IndexMut<isize> for Frontier {
+    fn index_mut(&mut self, k: isize) -> &mut usize {
+        &mut self.values[(k + self.offset) as usize]
+    }
+}
+
+/// Myers' O(ND) difference, linear-space variant.
+pub fn myers<T: Eq>(old: &[T], new: &[T]) -> Vec<Edit> {
+    let mut matches = Vec::new();
+    conquer(old, 0..old.len(), new, 0..new.len(), &mut matches);
+    script(&matches, old.len(), new.len())
+}
+
+pub(crate) fn conquer<T: Eq>(old: &[T], range_old: Range<usize>, new: &[T], range_new: Range<usize>, matches: &mut Vec<(usize, usize)>) {
+    let depth = (range_old.len() + range_new.len()).div_ceil(2) + 1;
+    let mut forward = Frontier::new(depth);
+    let mut backward = Frontier::new(depth);
+    divide(old, range_old, new, range_new, &mut forward, &mut backward, matches);
+}
+
+fn divide<T: Eq>(
+    old: &[T],
+    range_old: Range<usize>,
+    new: &[T],
+    range_new: Range<usize>,
+    forward: &mut Frontier,
+    backward: &mut Frontier,
+    matches: &mut Vec<(usize, usize)>,
+) {
+    let mut suffix = Vec::new();
+    let (range_old, range_new) = trim(old, range_old, new, range_new, matches, &mut suffix);
+
+    if !range_old.is_empty() && !range_new.is_empty() {
+        if let Some((split_old, split_new)) = snake(old, range_old.clone(), new, range_new.clone(), forward, backward) {
+            divide(old, range_old.start..split_old, new, range_new.start..split_new, forward, backward, matches);
+            divide(old, split_old..range_old.end, new, split_new..range_new.end, forward, backward, matches);
+        }
+    }
+
+    matches.extend(suffix.into_iter().rev());
+}
+
+/// Finds the start of the middle snake of an optimal path through the edit graph.
+fn snake<T: Eq>(
+    old: &[T],
+    range_old: Range<usize>,
+    new: &[T],
+    range_new: Range<usize>,
+    forward: &mut Frontier,
+    backward: &mut Frontier,
+) -> Option<(usize, usize)> {
+    let n = range_old.len();
+    let m = range_new.len();
+    let delta = n as isize - m as isize;
+    let odd = delta & 1 == 1;
+
+    forward[1] = 0;
+    backward[1] = 0;
+
+    let limit = (n + m).div_ceil(2) as isize + 1;
+    for d in 0..limit {
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x = if k == -d || (k != d && forward[k - 1] < forward[k + 1]) {
+                forward[k + 1]
+            } else {
+                forward[k - 1] + 1
+            };
+            let y = (x as isize - k) as usize;
+            let (start_x, start_y) = (x, y);
+
+            let mut y = y;
+            while x < n && y < m && old[range_old.start + x] == new[range_new.start + y] {
+                x += 1;
+                y += 1;
+            }
+            forward[k] = x;
+
+            if odd && (k - delta).abs() < d && forward[k] + backward[-(k - delta)] >= n {
+                return Some((range_old.start + start_x, range_new.start + start_y));
+            }
+        }
+
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x = if k == -d || (k != d && backward[k - 1] < backward[k + 1]) {
+                backward[k + 1]
+            } else {
+                backward[k - 1] + 1
+            };
+            let mut y = (x as isize - k) as usize;
+
+            while x < n && y < m && old[range_old.end - x - 1] == new[range_new.end - y - 1] {
+                x += 1;
+                y += 1;
+            }
+            backward[k] = x;
+
+            if !odd && (k - delta).abs() <= d && backward[k] + forward[-(k - delta)] >= n {
+                return Some((range_old.start + n - x, range_new.start + m - y));
+            }
+        }
+    }
+
+    None
+}
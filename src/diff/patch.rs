@@ -0,0 +1,105 @@
+use {
+    core::fmt::{Display, Formatter},
+    crate::diff::edit::{Edit, Operation},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PatchError {
+    Length { expected: usize, found: usize },
+    Mismatch { position: usize },
+}
+
+impl Display for PatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PatchError::Length { expected, found } => {
+                write!(f, "patch expects {} items but the target has {}", expected, found)
+            }
+            PatchError::Mismatch { position } => {
+                write!(f, "patch context does not match the target at item {}", position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+/// An edit together with the items it covers on both sides
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hunk<T> {
+    pub edit: Edit,
+    pub old: Vec<T>,
+    pub new: Vec<T>,
+}
+
+/// A self-contained edit script that can be replayed without the revision at hand
+#[derive(Clone, Debug, PartialEq)]
+pub struct Patch<T> {
+    pub hunks: Vec<Hunk<T>>,
+}
+
+/// Swaps both sides of a script so it turns the revision back into the original.
+pub fn invert(edits: &[Edit]) -> Vec<Edit> {
+    edits.iter()
+        .map(|edit| {
+            let operation = match edit.operation {
+                Operation::Insert => Operation::Delete,
+                Operation::Delete => Operation::Insert,
+                operation => operation,
+            };
+            Edit::new(operation, edit.new.clone(), edit.old.clone())
+        })
+        .collect()
+}
+
+impl<T: Clone + PartialEq> Patch<T> {
+    pub fn new(old: &[T], new: &[T], edits: &[Edit]) -> Self {
+        let hunks = edits.iter()
+            .map(|edit| Hunk {
+                edit: edit.clone(),
+                old: old[edit.old.clone()].to_vec(),
+                new: new[edit.new.clone()].to_vec(),
+            })
+            .collect();
+
+        Self { hunks }
+    }
+
+    /// Replays the patch, checking that every unchanged and removed item is still in place.
+    pub fn apply(&self, target: &[T]) -> Result<Vec<T>, PatchError> {
+        let expected = self.hunks.last().map_or(0, |hunk| hunk.edit.old.end);
+        if target.len() != expected {
+            return Err(PatchError::Length { expected, found: target.len() });
+        }
+
+        let mut result = Vec::with_capacity(self.hunks.last().map_or(0, |hunk| hunk.edit.new.end));
+        let mut position = 0;
+
+        for hunk in &self.hunks {
+            if hunk.edit.old.start != position {
+                return Err(PatchError::Mismatch { position });
+            }
+
+            let covered = &target[hunk.edit.old.clone()];
+            if let Some(offset) = covered.iter().zip(&hunk.old).position(|(found, recorded)| found != recorded) {
+                return Err(PatchError::Mismatch { position: position + offset });
+            }
+
+            result.extend_from_slice(&hunk.new);
+            position = hunk.edit.old.end;
+        }
+
+        Ok(result)
+    }
+
+    pub fn invert(&self) -> Self {
+        let edits: Vec<Edit> = self.hunks.iter().map(|hunk| hunk.edit.clone()).collect();
+        let hunks = invert(&edits)
+            .into_iter()
+            .zip(&self.hunks)
+            .map(|(edit, hunk)| Hunk { edit, old: hunk.new.clone(), new: hunk.old.clone() })
+            .collect();
+
+        Self { hunks }
+    }
+}
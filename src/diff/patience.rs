@@ -0,0 +1,92 @@
+use {
+    core::{hash::Hash, ops::Range},
+    hashish::HashMap,
+    crate::diff::{
+        edit::{script, trim, Edit},
+        myers::conquer,
+    },
+};
+
+/// Patience diff: anchors on items unique to both sides, falling back to Myers between anchors.
+pub fn patience<T: Eq + Hash>(old: &[T], new: &[T]) -> Vec<Edit> {
+    let mut matches = Vec::new();
+    anchor(old, 0..old.len(), new, 0..new.len(), &mut matches);
+    script(&matches, old.len(), new.len())
+}
+
+fn anchor<T: Eq + Hash>(old: &[T], range_old: Range<usize>, new: &[T], range_new: Range<usize>, matches: &mut Vec<(usize, usize)>) {
+    let mut suffix = Vec::new();
+    let (range_old, range_new) = trim(old, range_old, new, range_new, matches, &mut suffix);
+
+    if !range_old.is_empty() && !range_new.is_empty() {
+        let anchors = unique_common(old, range_old.clone(), new, range_new.clone());
+
+        if anchors.is_empty() {
+            conquer(old, range_old, new, range_new, matches);
+        } else {
+            let (mut start_old, mut start_new) = (range_old.start, range_new.start);
+            for (index_old, index_new) in anchors {
+                anchor(old, start_old..index_old, new, start_new..index_new, matches);
+                matches.push((index_old, index_new));
+                start_old = index_old + 1;
+                start_new = index_new + 1;
+            }
+            anchor(old, start_old..range_old.end, new, start_new..range_new.end, matches);
+        }
+    }
+
+    matches.extend(suffix.into_iter().rev());
+}
+
+/// Items occurring exactly once on each side, reduced to their longest increasing run.
+fn unique_common<T: Eq + Hash>(old: &[T], range_old: Range<usize>, new: &[T], range_new: Range<usize>) -> Vec<(usize, usize)> {
+    let mut counts: HashMap<&T, (usize, usize, usize)> = HashMap::new();
+
+    for index in range_old {
+        let count = counts.entry(&old[index]).or_insert((0, 0, 0));
+        count.0 += 1;
+        count.2 = index;
+    }
+
+    let mut pairs = Vec::new();
+    for index in range_new.clone() {
+        if let Some(count) = counts.get_mut(&new[index]) {
+            count.1 += 1;
+        }
+    }
+    for index in range_new {
+        if let Some(&(1, 1, index_old)) = counts.get(&new[index]) {
+            pairs.push((index_old, index));
+        }
+    }
+
+    longest_increasing(pairs)
+}
+
+/// Longest subsequence increasing in `old`, given pairs already increasing in `new` (patience sorting).
+fn longest_increasing(pairs: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous: Vec<Option<usize>> = vec![None; pairs.len()];
+
+    for (position, &(index_old, _)) in pairs.iter().enumerate() {
+        let pile = tails.partition_point(|&tail| pairs[tail].0 < index_old);
+        if pile > 0 {
+            previous[position] = Some(tails[pile - 1]);
+        }
+        if pile == tails.len() {
+            tails.push(position);
+        } else {
+            tails[pile] = position;
+        }
+    }
+
+    let mut sequence = Vec::with_capacity(tails.len());
+    let mut cursor = tails.last().copied();
+    while let Some(position) = cursor {
+        sequence.push(pairs[position]);
+        cursor = previous[position];
+    }
+
+    sequence.reverse();
+    sequence
+}
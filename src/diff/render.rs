@@ -0,0 +1,273 @@
+use {
+    core::ops::Range,
+    crate::diff::{
+        edit::{Edit, Operation},
+        text::TextDiff,
+    },
+};
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// Wraps `text` in an ANSI style; coloring can only be switched on with the `ansi` feature.
+fn paint(color: bool, style: &str, text: &str) -> String {
+    if color && !text.is_empty() {
+        format!("{}{}{}", style, text, RESET)
+    } else {
+        text.to_string()
+    }
+}
+
+fn strip(line: &str) -> &str {
+    line.strip_suffix('\n').map(|line| line.strip_suffix('\r').unwrap_or(line)).unwrap_or(line)
+}
+
+/// Unified diff over a line-granular [`TextDiff`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unified {
+    context: usize,
+    old_label: String,
+    new_label: String,
+    color: bool,
+}
+
+impl Default for Unified {
+    fn default() -> Self {
+        Self {
+            context: 3,
+            old_label: String::from("old"),
+            new_label: String::from("new"),
+            color: false,
+        }
+    }
+}
+
+impl Unified {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn context(mut self, context: usize) -> Self {
+        self.context = context;
+        self
+    }
+
+    pub fn labels(mut self, old_label: &str, new_label: &str) -> Self {
+        self.old_label = old_label.to_string();
+        self.new_label = new_label.to_string();
+        self
+    }
+
+    #[cfg(feature = "ansi")]
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Groups changes into hunks, each padded with up to `context` unchanged lines.
+    fn hunks(&self, edits: &[Edit]) -> Vec<(Range<usize>, Range<usize>)> {
+        let mut hunks: Vec<(Range<usize>, Range<usize>)> = Vec::new();
+        let old_end = edits.last().map_or(0, |edit| edit.old.end);
+        let new_end = edits.last().map_or(0, |edit| edit.new.end);
+
+        for edit in edits.iter().filter(|edit| !edit.is_equal()) {
+            let old = edit.old.start.saturating_sub(self.context)..(edit.old.end + self.context).min(old_end);
+            let lead = edit.old.start - old.start;
+            let new = edit.new.start - lead..(edit.new.end + self.context).min(new_end);
+
+            match hunks.last_mut() {
+                Some((last_old, last_new)) if old.start <= last_old.end => {
+                    last_old.end = old.end;
+                    last_new.end = new.end;
+                }
+                _ => hunks.push((old, new)),
+            }
+        }
+
+        hunks
+    }
+
+    pub fn render(&self, diff: &TextDiff) -> String {
+        let mut output = String::new();
+        let hunks = self.hunks(&diff.edits);
+        if hunks.is_empty() {
+            return output;
+        }
+
+        output.push_str(&paint(self.color, BOLD, &format!("--- {}", self.old_label)));
+        output.push('\n');
+        output.push_str(&paint(self.color, BOLD, &format!("+++ {}", self.new_label)));
+        output.push('\n');
+
+        for (old, new) in hunks {
+            let start = |range: &Range<usize>| if range.is_empty() { range.start } else { range.start + 1 };
+            let header = format!("@@ -{},{} +{},{} @@", start(&old), old.len(), start(&new), new.len());
+            output.push_str(&paint(self.color, CYAN, &header));
+            output.push('\n');
+
+            for edit in &diff.edits {
+                let old_lines = edit.old.start.max(old.start)..edit.old.end.min(old.end);
+                let new_lines = edit.new.start.max(new.start)..edit.new.end.min(new.end);
+
+                match edit.operation {
+                    Operation::Equal => {
+                        for line in old_lines.clone() {
+                            self.line(&mut output, ' ', diff.old[line], "");
+                        }
+                    }
+                    _ => {
+                        for line in old_lines.clone() {
+                            self.line(&mut output, '-', diff.old[line], RED);
+                        }
+                        for line in new_lines.clone() {
+                            self.line(&mut output, '+', diff.new[line], GREEN);
+                        }
+                    }
+                }
+            }
+        }
+
+        output
+    }
+
+    fn line(&self, output: &mut String, marker: char, line: &str, style: &str) {
+        let text = format!("{}{}", marker, strip(line));
+        output.push_str(&paint(self.color && !style.is_empty(), style, &text));
+        output.push('\n');
+        if !line.ends_with('\n') {
+            output.push_str("\\ No newline at end of file\n");
+        }
+    }
+}
+
+/// Two-column view over a line-granular [`TextDiff`], marking rows with `|`, `<` or `>`
+#[derive(Clone, Debug, PartialEq)]
+pub struct SideBySide {
+    width: usize,
+    color: bool,
+}
+
+impl Default for SideBySide {
+    fn default() -> Self {
+        Self { width: 40, color: false }
+    }
+}
+
+impl SideBySide {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    #[cfg(feature = "ansi")]
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    fn column(&self, text: &str) -> String {
+        let mut column: String = strip(text).chars().take(self.width).collect();
+        let length = column.chars().count();
+        column.extend(core::iter::repeat_n(' ', self.width - length));
+        column
+    }
+
+    pub fn render(&self, diff: &TextDiff) -> String {
+        let mut output = String::new();
+
+        for edit in &diff.edits {
+            let rows = edit.old.len().max(edit.new.len());
+            for row in 0..rows {
+                let left = (row < edit.old.len()).then(|| diff.old[edit.old.start + row]);
+                let right = (row < edit.new.len()).then(|| diff.new[edit.new.start + row]);
+
+                let (marker, left_style, right_style) = match (edit.operation, left, right) {
+                    (Operation::Equal, _, _) => (' ', "", ""),
+                    (_, Some(_), Some(_)) => ('|', RED, GREEN),
+                    (_, Some(_), None) => ('<', RED, ""),
+                    _ => ('>', "", GREEN),
+                };
+
+                let left = self.column(left.unwrap_or(""));
+                let right = strip(right.unwrap_or(""));
+                let line = format!(
+                    "{} {} {}",
+                    paint(self.color && !left_style.is_empty(), left_style, &left),
+                    marker,
+                    paint(self.color && !right_style.is_empty(), right_style, right),
+                );
+                output.push_str(line.trim_end());
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+}
+
+/// Inline view over a word- or char-granular [`TextDiff`], wrapping changes in markers
+#[derive(Clone, Debug, PartialEq)]
+pub struct Inline {
+    deleted: (String, String),
+    inserted: (String, String),
+    color: bool,
+}
+
+impl Default for Inline {
+    fn default() -> Self {
+        Self {
+            deleted: (String::from("[-"), String::from("-]")),
+            inserted: (String::from("{+"), String::from("+}")),
+            color: false,
+        }
+    }
+}
+
+impl Inline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn markers(mut self, deleted: (&str, &str), inserted: (&str, &str)) -> Self {
+        self.deleted = (deleted.0.to_string(), deleted.1.to_string());
+        self.inserted = (inserted.0.to_string(), inserted.1.to_string());
+        self
+    }
+
+    #[cfg(feature = "ansi")]
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn render(&self, diff: &TextDiff) -> String {
+        let mut output = String::new();
+
+        for edit in &diff.edits {
+            let old = diff.old_text(edit.old.clone());
+            let new = diff.new_text(edit.new.clone());
+
+            if edit.is_equal() {
+                output.push_str(&old);
+                continue;
+            }
+            if !old.is_empty() {
+                let marked = format!("{}{}{}", self.deleted.0, old, self.deleted.1);
+                output.push_str(&paint(self.color, RED, &marked));
+            }
+            if !new.is_empty() {
+                let marked = format!("{}{}{}", self.inserted.0, new, self.inserted.1);
+                output.push_str(&paint(self.color, GREEN, &marked));
+            }
+        }
+
+        output
+    }
+}
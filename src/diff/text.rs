@@ -0,0 +1,93 @@
+use {
+    core::ops::Range,
+    crate::diff::{
+        edit::Edit,
+        Algorithm,
+    },
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Granularity {
+    Char,
+    #[default]
+    Word,
+    Line,
+}
+
+impl Granularity {
+    /// Splits text into tokens that concatenate back to the original.
+    ///
+    /// Words are runs of alphanumerics, runs of whitespace, or single punctuation marks;
+    /// lines keep their trailing newline.
+    pub fn tokenize<'text>(&self, text: &'text str) -> Vec<&'text str> {
+        match self {
+            Granularity::Char => text.char_indices()
+                .map(|(start, c)| &text[start..start + c.len_utf8()])
+                .collect(),
+            Granularity::Word => {
+                let mut tokens = Vec::new();
+                let mut start = 0;
+                let mut previous: Option<u8> = None;
+
+                for (position, c) in text.char_indices() {
+                    let class = if c.is_alphanumeric() || c == '_' {
+                        0
+                    } else if c.is_whitespace() {
+                        1
+                    } else {
+                        2
+                    };
+
+                    if position > start && (previous != Some(class) || class == 2) {
+                        tokens.push(&text[start..position]);
+                        start = position;
+                    }
+                    previous = Some(class);
+                }
+
+                if start < text.len() {
+                    tokens.push(&text[start..]);
+                }
+                tokens
+            }
+            Granularity::Line => text.split_inclusive('\n').collect(),
+        }
+    }
+}
+
+/// An edit script over the tokens of two strings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextDiff<'text> {
+    pub old: Vec<&'text str>,
+    pub new: Vec<&'text str>,
+    pub edits: Vec<Edit>,
+}
+
+impl<'text> TextDiff<'text> {
+    pub fn new(old: &'text str, new: &'text str, granularity: Granularity, algorithm: Algorithm) -> Self {
+        let old = granularity.tokenize(old);
+        let new = granularity.tokenize(new);
+        let edits = algorithm.diff(&old, &new);
+        Self { old, new, edits }
+    }
+
+    pub fn chars(old: &'text str, new: &'text str) -> Self {
+        Self::new(old, new, Granularity::Char, Algorithm::default())
+    }
+
+    pub fn words(old: &'text str, new: &'text str) -> Self {
+        Self::new(old, new, Granularity::Word, Algorithm::default())
+    }
+
+    pub fn lines(old: &'text str, new: &'text str) -> Self {
+        Self::new(old, new, Granularity::Line, Algorithm::default())
+    }
+
+    pub fn old_text(&self, range: Range<usize>) -> String {
+        self.old[range].concat()
+    }
+
+    pub fn new_text(&self, range: Range<usize>) -> String {
+        self.new[range].concat()
+    }
+}
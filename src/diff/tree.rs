@@ -0,0 +1,440 @@
+use {
+    core::{
+        fmt::{Debug, Display, Formatter},
+        hash::{Hash, Hasher},
+    },
+    std::collections::BTreeMap,
+    crate::{
+        assessor::{Resembler, Resemblance},
+        diff::{edit::Operation, myers::myers},
+    },
+};
+
+#[derive(Clone, Debug)]
+pub enum Scalar {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Text(String),
+}
+
+impl PartialEq for Scalar {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Scalar::Null, Scalar::Null) => true,
+            (Scalar::Bool(a), Scalar::Bool(b)) => a == b,
+            (Scalar::Number(a), Scalar::Number(b)) => a == b || (a.is_nan() && b.is_nan()),
+            (Scalar::Text(a), Scalar::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Numbers compare by value, with every NaN equal to every other.
+impl Eq for Scalar {}
+
+impl Hash for Scalar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        match self {
+            Scalar::Null => {}
+            Scalar::Bool(value) => value.hash(state),
+            Scalar::Number(value) => {
+                let canonical = if value.is_nan() { f64::NAN } else if *value == 0.0 { 0.0 } else { *value };
+                canonical.to_bits().hash(state)
+            }
+            Scalar::Text(value) => value.hash(state),
+        }
+    }
+}
+
+/// A JSON-like tree node
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Value {
+    Map(BTreeMap<String, Value>),
+    Sequence(Vec<Value>),
+    Scalar(Scalar),
+}
+
+impl Value {
+    pub fn map<K: Into<String>, I: IntoIterator<Item = (K, Value)>>(entries: I) -> Self {
+        Value::Map(entries.into_iter().map(|(key, value)| (key.into(), value)).collect())
+    }
+
+    pub fn sequence<I: IntoIterator<Item = Value>>(items: I) -> Self {
+        Value::Sequence(items.into_iter().collect())
+    }
+
+    pub fn null() -> Self {
+        Value::Scalar(Scalar::Null)
+    }
+
+    pub fn is_compound(&self) -> bool {
+        !matches!(self, Value::Scalar(_))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Map(entries) => entries.get(key),
+            _ => None,
+        }
+    }
+
+    /// Every scalar in the tree paired with its location.
+    pub fn leaves(&self) -> Vec<(Location, &Scalar)> {
+        let mut leaves = Vec::new();
+        self.collect_leaves(Location::root(), &mut leaves);
+        leaves
+    }
+
+    fn collect_leaves<'value>(&'value self, location: Location, leaves: &mut Vec<(Location, &'value Scalar)>) {
+        match self {
+            Value::Map(entries) => {
+                for (key, value) in entries {
+                    value.collect_leaves(location.key(key), leaves);
+                }
+            }
+            Value::Sequence(items) => {
+                for (index, value) in items.iter().enumerate() {
+                    value.collect_leaves(location.index(index), leaves);
+                }
+            }
+            Value::Scalar(scalar) => leaves.push((location, scalar)),
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Scalar(Scalar::Bool(value))
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Scalar(Scalar::Number(value))
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Scalar(Scalar::Number(value as f64))
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Scalar(Scalar::Text(value.to_string()))
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Scalar(Scalar::Text(value))
+    }
+}
+
+impl From<Vec<Value>> for Value {
+    fn from(items: Vec<Value>) -> Self {
+        Value::Sequence(items)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Path from the root of a tree to one of its nodes, displayed as `a.b[3].c`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Location {
+    pub segments: Vec<Segment>,
+}
+
+impl Location {
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    pub fn key(&self, key: &str) -> Self {
+        let mut segments = self.segments.clone();
+        segments.push(Segment::Key(key.to_string()));
+        Self { segments }
+    }
+
+    pub fn index(&self, index: usize) -> Self {
+        let mut segments = self.segments.clone();
+        segments.push(Segment::Index(index));
+        Self { segments }
+    }
+
+    pub fn is_element(&self) -> bool {
+        matches!(self.segments.last(), Some(Segment::Index(_)))
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (position, segment) in self.segments.iter().enumerate() {
+            match segment {
+                Segment::Key(key) => {
+                    let plain = !key.is_empty() && key.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+                    if !plain {
+                        write!(f, "[{:?}]", key)?;
+                    } else if position == 0 {
+                        write!(f, "{}", key)?;
+                    } else {
+                        write!(f, ".{}", key)?;
+                    }
+                }
+                Segment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A single difference between two trees.
+///
+/// `Removed` locations and `Moved::from` address the old tree; all others address the new one.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    Added { location: Location, value: Value },
+    Removed { location: Location, value: Value },
+    Changed { location: Location, old: Value, new: Value },
+    Moved { from: Location, to: Location, value: Value },
+}
+
+impl Change {
+    pub fn location(&self) -> &Location {
+        match self {
+            Change::Added { location, .. } | Change::Removed { location, .. } | Change::Changed { location, .. } => location,
+            Change::Moved { to, .. } => to,
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Change::Added { location, .. } => write!(f, "+ {}", location),
+            Change::Removed { location, .. } => write!(f, "- {}", location),
+            Change::Changed { location, .. } => write!(f, "~ {}", location),
+            Change::Moved { from, to, .. } => write!(f, "> {} -> {}", from, to),
+        }
+    }
+}
+
+/// Share of leaves two trees have in common at the same relative location
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Overlap;
+
+pub fn overlap(query: &Value, candidate: &Value) -> f64 {
+    let query_leaves = query.leaves();
+    let candidate_leaves = candidate.leaves();
+
+    if query_leaves.is_empty() && candidate_leaves.is_empty() {
+        return if query == candidate { 1.0 } else { 0.0 };
+    }
+
+    let common = query_leaves.iter().filter(|leaf| candidate_leaves.contains(leaf)).count();
+    2.0 * common as f64 / (query_leaves.len() + candidate_leaves.len()) as f64
+}
+
+impl<Error> Resembler<Value, Value, Error> for Overlap {
+    fn resemblance(&mut self, query: &Value, candidate: &Value) -> Result<Resemblance, Error> {
+        Ok(overlap(query, candidate).into())
+    }
+}
+
+/// Maps holding the same value under an identifying key, such as `"id"`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Identity {
+    key: String,
+}
+
+impl Identity {
+    pub fn new(key: &str) -> Self {
+        Self { key: key.to_string() }
+    }
+}
+
+impl<Error> Resembler<Value, Value, Error> for Identity {
+    fn resemblance(&mut self, query: &Value, candidate: &Value) -> Result<Resemblance, Error> {
+        match (query.get(&self.key), candidate.get(&self.key)) {
+            (Some(query_id), Some(candidate_id)) if query_id == candidate_id => Ok(Resemblance::Perfect),
+            _ => Ok(Resemblance::Disparity),
+        }
+    }
+}
+
+/// Path-addressed differ for [`Value`] trees.
+///
+/// Sequence elements that do not match exactly are paired up as "the same item, modified"
+/// when the resembler scores them at or above the floor; by default that resembler is [`Overlap`].
+pub struct TreeDiff<'tree, Error> {
+    resembler: Option<&'tree mut dyn Resembler<Value, Value, Error>>,
+    floor: f64,
+}
+
+impl<Error> Debug for TreeDiff<'_, Error> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TreeDiff")
+            .field("resembler", &self.resembler)
+            .field("floor", &self.floor)
+            .finish()
+    }
+}
+
+impl<Error> Default for TreeDiff<'_, Error> {
+    fn default() -> Self {
+        Self { resembler: None, floor: 0.5 }
+    }
+}
+
+impl<'tree, Error> TreeDiff<'tree, Error> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn resembler<R: Resembler<Value, Value, Error>>(mut self, resembler: &'tree mut R) -> Self {
+        self.resembler = Some(resembler);
+        self
+    }
+
+    pub fn floor(mut self, floor: f64) -> Self {
+        self.floor = floor;
+        self
+    }
+
+    pub fn diff(&mut self, old: &Value, new: &Value) -> Result<Vec<Change>, Error> {
+        let mut changes = Vec::new();
+        self.compare(old, new, Location::root(), Location::root(), &mut changes)?;
+        Ok(detect_moves(changes))
+    }
+
+    fn score(&mut self, old: &Value, new: &Value) -> Result<f64, Error> {
+        match self.resembler.as_mut() {
+            Some(resembler) => Ok(resembler.resemblance(old, new)?.to_f64()),
+            None => Ok(overlap(old, new)),
+        }
+    }
+
+    /// Compares two values found at `from` in the old tree and at `to` in the new one.
+    fn compare(&mut self, old: &Value, new: &Value, from: Location, to: Location, changes: &mut Vec<Change>) -> Result<(), Error> {
+        if old == new {
+            return Ok(());
+        }
+
+        match (old, new) {
+            (Value::Map(old_entries), Value::Map(new_entries)) => {
+                for (key, old_value) in old_entries {
+                    match new_entries.get(key) {
+                        Some(new_value) => self.compare(old_value, new_value, from.key(key), to.key(key), changes)?,
+                        None => changes.push(Change::Removed { location: from.key(key), value: old_value.clone() }),
+                    }
+                }
+                for (key, new_value) in new_entries {
+                    if !old_entries.contains_key(key) {
+                        changes.push(Change::Added { location: to.key(key), value: new_value.clone() });
+                    }
+                }
+            }
+            (Value::Sequence(old_items), Value::Sequence(new_items)) => {
+                self.sequence(old_items, new_items, from, to, changes)?;
+            }
+            _ => changes.push(Change::Changed { location: to, old: old.clone(), new: new.clone() }),
+        }
+
+        Ok(())
+    }
+
+    fn sequence(&mut self, old: &[Value], new: &[Value], from: Location, to: Location, changes: &mut Vec<Change>) -> Result<(), Error> {
+        for edit in myers(old, new) {
+            match edit.operation {
+                Operation::Equal => {}
+                Operation::Delete => {
+                    for index in edit.old {
+                        changes.push(Change::Removed { location: from.index(index), value: old[index].clone() });
+                    }
+                }
+                Operation::Insert => {
+                    for index in edit.new {
+                        changes.push(Change::Added { location: to.index(index), value: new[index].clone() });
+                    }
+                }
+                Operation::Replace => {
+                    let mut scores = Vec::new();
+                    for index_old in edit.old.clone() {
+                        for index_new in edit.new.clone() {
+                            let score = self.score(&old[index_old], &new[index_new])?;
+                            if score >= self.floor {
+                                scores.push((score, index_old, index_new));
+                            }
+                        }
+                    }
+                    scores.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+
+                    let mut paired_old = vec![None; edit.old.len()];
+                    let mut paired_new = vec![false; edit.new.len()];
+                    for (_, index_old, index_new) in scores {
+                        let (slot_old, slot_new) = (index_old - edit.old.start, index_new - edit.new.start);
+                        if paired_old[slot_old].is_none() && !paired_new[slot_new] {
+                            paired_old[slot_old] = Some(index_new);
+                            paired_new[slot_new] = true;
+                        }
+                    }
+
+                    for (slot, pair) in paired_old.iter().enumerate() {
+                        let index_old = edit.old.start + slot;
+                        match pair {
+                            Some(index_new) => self.compare(&old[index_old], &new[*index_new], from.index(index_old), to.index(*index_new), changes)?,
+                            None => changes.push(Change::Removed { location: from.index(index_old), value: old[index_old].clone() }),
+                        }
+                    }
+                    for (slot, paired) in paired_new.iter().enumerate() {
+                        if !paired {
+                            let index_new = edit.new.start + slot;
+                            changes.push(Change::Added { location: to.index(index_new), value: new[index_new].clone() });
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Folds a removal and an addition of the same value into a move, for sequence elements
+/// and compound values only so that coincidentally equal scalars stay separate.
+fn detect_moves(changes: Vec<Change>) -> Vec<Change> {
+    let movable = |location: &Location, value: &Value| location.is_element() || value.is_compound();
+    let mut slots: Vec<Option<Change>> = changes.into_iter().map(Some).collect();
+    let mut moves = Vec::new();
+
+    for removal in 0..slots.len() {
+        let (from, value) = match &slots[removal] {
+            Some(Change::Removed { location, value }) if movable(location, value) => (location.clone(), value.clone()),
+            _ => continue,
+        };
+
+        let addition = slots.iter().position(|slot| {
+            matches!(slot, Some(Change::Added { location, value: added }) if added == &value && movable(location, added))
+        });
+
+        if let Some(addition) = addition {
+            if let Some(Change::Added { location, .. }) = slots[addition].take() {
+                slots[removal] = None;
+                moves.push((removal, Change::Moved { from, to: location, value }));
+            }
+        }
+    }
+
+    for (position, change) in moves {
+        slots[position] = Some(change);
+    }
+
+    slots.into_iter().flatten().collect()
+}
@@ -0,0 +1,148 @@
+use {
+    hashish::HashMap,
+    std::collections::BTreeMap,
+};
+
+/// A candidate stored in an index
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub text: String,
+    pub normalized: String,
+    pub grams: Vec<String>,
+    pub weight: f64,
+}
+
+/// Candidate index with normalized strings and n-gram postings
+#[derive(Clone, Debug, PartialEq)]
+pub struct Index {
+    size: usize,
+    entries: Vec<Entry>,
+    postings: BTreeMap<String, Vec<u32>>,
+    metadata: Vec<(String, String)>,
+}
+
+impl Default for Index {
+    fn default() -> Self {
+        Self {
+            size: 2,
+            entries: Vec::new(),
+            postings: BTreeMap::new(),
+            metadata: Vec::new(),
+        }
+    }
+}
+
+pub fn normalize(text: &str) -> String {
+    text.trim().to_lowercase()
+}
+
+pub fn ngrams(text: &str, size: usize) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+
+    let mut grams: Vec<String> = if chars.len() < size {
+        if chars.is_empty() { Vec::new() } else { vec![text.to_string()] }
+    } else {
+        chars.windows(size).map(|window| window.iter().collect()).collect()
+    };
+
+    grams.sort();
+    grams.dedup();
+    grams
+}
+
+impl Index {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn size(mut self, size: usize) -> Self {
+        self.size = size.max(1);
+
+        let entries = core::mem::take(&mut self.entries);
+        self.postings.clear();
+        for entry in entries {
+            self.insert(&entry.text, entry.weight);
+        }
+
+        self
+    }
+
+    pub fn metadata(mut self, key: &str, value: &str) -> Self {
+        self.metadata.retain(|(existing, _)| existing != key);
+        self.metadata.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn entry(mut self, text: &str, weight: f64) -> Self {
+        self.insert(text, weight);
+        self
+    }
+
+    pub fn insert(&mut self, text: &str, weight: f64) -> usize {
+        let id = self.entries.len();
+        let normalized = normalize(text);
+        let grams = ngrams(&normalized, self.size);
+
+        for gram in &grams {
+            self.postings.entry(gram.clone()).or_default().push(id as u32);
+        }
+
+        self.entries.push(Entry {
+            text: text.to_string(),
+            normalized,
+            grams,
+            weight,
+        });
+
+        id
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get_entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn get_postings(&self) -> &BTreeMap<String, Vec<u32>> {
+        &self.postings
+    }
+
+    pub fn get_metadata(&self, key: &str) -> Option<&str> {
+        self.metadata.iter().find(|(existing, _)| existing == key).map(|(_, value)| value.as_str())
+    }
+
+    pub fn get_all_metadata(&self) -> &[(String, String)] {
+        &self.metadata
+    }
+
+    /// Entry ids sharing at least one n-gram with the query, most shared first.
+    pub fn candidates(&self, query: &str) -> Vec<usize> {
+        let grams = ngrams(&normalize(query), self.size);
+        rank(grams.iter().filter_map(|gram| self.postings.get(gram)).flatten().map(|&id| id as usize))
+    }
+
+    pub(crate) fn restore(size: usize, entries: Vec<Entry>, postings: BTreeMap<String, Vec<u32>>, metadata: Vec<(String, String)>) -> Self {
+        Self { size, entries, postings, metadata }
+    }
+}
+
+pub(crate) fn rank(hits: impl Iterator<Item = usize>) -> Vec<usize> {
+    let mut shared: HashMap<usize, usize> = HashMap::new();
+    for id in hits {
+        *shared.entry(id).or_insert(0) += 1;
+    }
+
+    let mut ranked: Vec<(usize, usize)> = shared.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    ranked.into_iter().map(|(id, _)| id).collect()
+}
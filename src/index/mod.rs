@@ -0,0 +1,7 @@
+pub mod candidate;
+pub mod prefix;
+pub mod storage;
+
+pub use candidate::*;
+pub use prefix::*;
+pub use storage::*;
\ No newline at end of file
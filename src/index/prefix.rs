@@ -0,0 +1,226 @@
+use {
+    core::cmp::Ordering,
+    hashish::HashMap,
+    std::collections::BinaryHeap,
+    crate::index::candidate::{normalize, Index},
+};
+
+/// A ranked completion produced by a [`Trie`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Completion {
+    pub text: String,
+    pub weight: f64,
+    pub distance: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Node {
+    children: Vec<(char, usize)>,
+    terminal: Vec<usize>,
+    best: f64,
+}
+
+impl Node {
+    fn child(&self, key: char) -> Option<usize> {
+        self.children
+            .binary_search_by(|(existing, _)| existing.cmp(&key))
+            .ok()
+            .map(|position| self.children[position].1)
+    }
+}
+
+/// Prefix completion index over normalized candidates
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trie {
+    nodes: Vec<Node>,
+    entries: Vec<(String, f64)>,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self {
+            nodes: vec![Node { best: f64::NEG_INFINITY, ..Node::default() }],
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl From<&Index> for Trie {
+    fn from(index: &Index) -> Self {
+        let mut trie = Trie::new();
+        for entry in index.get_entries() {
+            trie.insert(&entry.text, entry.weight);
+        }
+        trie
+    }
+}
+
+/// Heap item for best-first traversal: `(distance, weight)` with the smallest distance
+/// and then the largest weight on top.
+#[derive(PartialEq)]
+enum Pending {
+    Node { node: usize, distance: usize, weight: f64 },
+    Entry { entry: usize, distance: usize, weight: f64 },
+}
+
+impl Pending {
+    fn key(&self) -> (usize, f64) {
+        match self {
+            Pending::Node { distance, weight, .. } | Pending::Entry { distance, weight, .. } => (*distance, *weight),
+        }
+    }
+}
+
+impl Eq for Pending {}
+
+impl PartialOrd for Pending {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Pending {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (distance, weight) = self.key();
+        let (other_distance, other_weight) = other.key();
+        other_distance.cmp(&distance)
+            .then(weight.total_cmp(&other_weight))
+            .then(matches!(self, Pending::Entry { .. }).cmp(&matches!(other, Pending::Entry { .. })))
+    }
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn entry(mut self, text: &str, weight: f64) -> Self {
+        self.insert(text, weight);
+        self
+    }
+
+    pub fn insert(&mut self, text: &str, weight: f64) {
+        let mut node = 0;
+        self.nodes[node].best = self.nodes[node].best.max(weight);
+
+        for key in normalize(text).chars() {
+            node = match self.nodes[node].children.binary_search_by(|(existing, _)| existing.cmp(&key)) {
+                Ok(position) => self.nodes[node].children[position].1,
+                Err(position) => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node { best: weight, ..Node::default() });
+                    self.nodes[node].children.insert(position, (key, child));
+                    child
+                }
+            };
+            self.nodes[node].best = self.nodes[node].best.max(weight);
+        }
+
+        let existing = self.nodes[node].terminal.iter().copied().find(|&entry| self.entries[entry].0 == text);
+        match existing {
+            Some(entry) => self.entries[entry].1 = weight,
+            None => {
+                self.nodes[node].terminal.push(self.entries.len());
+                self.entries.push((text.to_string(), weight));
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Candidates starting with `prefix`, heaviest first.
+    pub fn complete(&self, prefix: &str, limit: usize) -> Vec<Completion> {
+        let mut node = 0;
+        for key in normalize(prefix).chars() {
+            match self.nodes[node].child(key) {
+                Some(child) => node = child,
+                None => return Vec::new(),
+            }
+        }
+
+        let mut starts = HashMap::new();
+        starts.insert(node, 0);
+        self.collect(starts, limit)
+    }
+
+    /// Candidates whose prefix is within `typos` Levenshtein edits of `prefix`,
+    /// ranked by fewest edits and then by weight.
+    pub fn fuzzy(&self, prefix: &str, typos: usize, limit: usize) -> Vec<Completion> {
+        let query: Vec<char> = normalize(prefix).chars().collect();
+        let row: Vec<usize> = (0..=query.len()).collect();
+
+        let mut starts = HashMap::new();
+        self.walk(0, &query, &row, typos, usize::MAX, &mut starts);
+        self.collect(starts, limit)
+    }
+
+    /// Steps a Levenshtein automaton over the trie, recording every node where the
+    /// whole query is consumed within budget and deeper than any cheaper ancestor.
+    fn walk(&self, node: usize, query: &[char], row: &[usize], typos: usize, found: usize, starts: &mut HashMap<usize, usize>) {
+        let mut found = found;
+        let distance = row[query.len()];
+        if distance <= typos && distance < found {
+            starts.insert(node, distance);
+            found = distance;
+        }
+
+        let floor = row.iter().copied().min().unwrap_or(0);
+        if floor > typos || floor >= found {
+            return;
+        }
+
+        for &(key, child) in &self.nodes[node].children {
+            let mut next = Vec::with_capacity(row.len());
+            next.push(row[0] + 1);
+            for (position, expected) in query.iter().enumerate() {
+                let cost = if *expected == key { 0 } else { 1 };
+                let value = (row[position + 1] + 1)
+                    .min(next[position] + 1)
+                    .min(row[position] + cost);
+                next.push(value);
+            }
+            self.walk(child, query, &next, typos, found, starts);
+        }
+    }
+
+    fn collect(&self, starts: HashMap<usize, usize>, limit: usize) -> Vec<Completion> {
+        let mut heap: BinaryHeap<Pending> = starts.iter()
+            .map(|(&node, &distance)| Pending::Node { node, distance, weight: self.nodes[node].best })
+            .collect();
+        let mut completions = Vec::new();
+
+        while let Some(pending) = heap.pop() {
+            if completions.len() >= limit {
+                break;
+            }
+
+            match pending {
+                Pending::Entry { entry, distance, weight } => {
+                    completions.push(Completion {
+                        text: self.entries[entry].0.clone(),
+                        weight,
+                        distance,
+                    });
+                }
+                Pending::Node { node, distance, .. } => {
+                    for &entry in &self.nodes[node].terminal {
+                        heap.push(Pending::Entry { entry, distance, weight: self.entries[entry].1 });
+                    }
+                    for &(_, child) in &self.nodes[node].children {
+                        if !starts.contains_key(&child) {
+                            heap.push(Pending::Node { node: child, distance, weight: self.nodes[child].best });
+                        }
+                    }
+                }
+            }
+        }
+
+        completions
+    }
+}
@@ -0,0 +1,592 @@
+//! Binary on-disk format for a candidate [`Index`].
+//!
+//! Every integer is little-endian. A file is a 48-byte header followed by six
+//! tightly packed sections in this order:
+//!
+//! | Section      | Record layout                                                        | Record size |
+//! |--------------|----------------------------------------------------------------------|-------------|
+//! | `entries`    | text span, normalized span, reference span (`u32` pairs), weight `f64` | 32 bytes    |
+//! | `references` | gram id `u32`                                                        | 4 bytes     |
+//! | `grams`      | text span, posting span (`u32` pairs)                                | 16 bytes    |
+//! | `postings`   | entry id `u32`                                                       | 4 bytes     |
+//! | `metadata`   | key span, value span (`u32` pairs)                                   | 16 bytes    |
+//! | `blob`       | UTF-8 bytes every text span points into                              | 1 byte      |
+//!
+//! A span is an `(offset, length)` pair into the blob for text, or into the
+//! `references`/`postings` section for lists. Grams are stored in ascending byte
+//! order so they can be binary searched in place.
+//!
+//! The header holds the magic `b"MATCHETE"`, the format version, the n-gram size,
+//! the record count of each section (the blob count is in bytes), and finally a
+//! FNV-1a 64 checksum of every byte in the file except the checksum itself.
+//!
+//! ```text
+//! 0       8         12     16        20           24      28         32         36     40         48
+//! | magic | version | size | entries | references | grams | postings | metadata | blob | checksum |
+//! ```
+
+use {
+    core::fmt::{Debug, Display, Formatter},
+    hashish::HashMap,
+    memmap2::Mmap,
+    std::{
+        collections::BTreeMap,
+        fs::File,
+        io::Write,
+        path::Path,
+    },
+    crate::index::candidate::{rank, ngrams, normalize, Entry, Index},
+};
+
+pub const MAGIC: [u8; 8] = *b"MATCHETE";
+pub const VERSION: u32 = 1;
+
+pub(crate) const HEADER: usize = 48;
+pub(crate) const CHECKSUM: usize = 40;
+const ENTRY: usize = 32;
+const REFERENCE: usize = 4;
+const GRAM: usize = 16;
+const POSTING: usize = 4;
+const METADATA: usize = 16;
+
+#[derive(Debug)]
+pub enum IndexError {
+    Io(std::io::Error),
+    Magic,
+    Version { found: u32, expected: u32 },
+    Length { found: usize, expected: usize },
+    Checksum { found: u64, expected: u64 },
+    Corrupt(&'static str),
+    Overflow(&'static str),
+    /// The file was written with other word settings than those it is loaded with.
+    Settings { found: u64, expected: u64 },
+}
+
+impl Display for IndexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IndexError::Io(error) => write!(f, "index i/o failed: {}", error),
+            IndexError::Magic => write!(f, "not a matchete index: bad magic bytes"),
+            IndexError::Version { found, expected } => {
+                write!(f, "index format version {} is not supported, expected version {}", found, expected)
+            }
+            IndexError::Length { found, expected } => {
+                write!(f, "index is {} bytes long but its header describes {} bytes", found, expected)
+            }
+            IndexError::Checksum { found, expected } => {
+                write!(f, "index checksum mismatch: stored {:016x}, computed {:016x}", found, expected)
+            }
+            IndexError::Corrupt(reason) => write!(f, "index is corrupt: {}", reason),
+            IndexError::Overflow(what) => write!(f, "index is too large to encode: too many {}", what),
+            IndexError::Settings { found, expected } => {
+                write!(f, "file was written with other word settings: stored {:016x}, loading with {:016x}", found, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IndexError {}
+
+impl From<std::io::Error> for IndexError {
+    fn from(error: std::io::Error) -> Self {
+        IndexError::Io(error)
+    }
+}
+
+/// FNV-1a 64 over a byte stream.
+pub(crate) fn fnv1a<I: IntoIterator<Item = u8>>(bytes: I) -> u64 {
+    bytes.into_iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+pub(crate) fn checksum(bytes: &[u8]) -> u64 {
+    let body = bytes.iter().enumerate().filter(|(position, _)| !(CHECKSUM..HEADER).contains(position));
+    fnv1a(body.map(|(_, byte)| *byte))
+}
+
+/// Checks the length, magic bytes and version every matchete file opens with.
+pub(crate) fn read_header(bytes: &[u8], magic: [u8; 8], version: u32) -> Result<(), IndexError> {
+    if bytes.len() < HEADER {
+        return Err(IndexError::Length { found: bytes.len(), expected: HEADER });
+    }
+    if bytes[0..8] != magic {
+        return Err(IndexError::Magic);
+    }
+
+    let found = read_u32(bytes, 8);
+    if found != version {
+        return Err(IndexError::Version { found, expected: version });
+    }
+
+    Ok(())
+}
+
+/// Fails unless the checksum stored in the header matches the file's contents.
+pub(crate) fn verify(bytes: &[u8]) -> Result<(), IndexError> {
+    let found = read_u64(bytes, CHECKSUM);
+    let expected = checksum(bytes);
+    if found != expected {
+        return Err(IndexError::Checksum { found, expected });
+    }
+    Ok(())
+}
+
+/// Stores the checksum of a fully encoded file in its header.
+pub(crate) fn seal(bytes: &mut [u8]) {
+    let sum = checksum(bytes);
+    bytes[CHECKSUM..HEADER].copy_from_slice(&sum.to_le_bytes());
+}
+
+pub(crate) fn write_file<P: AsRef<Path>>(path: P, bytes: &[u8]) -> Result<(), IndexError> {
+    let mut file = File::create(path)?;
+    file.write_all(bytes)?;
+    file.sync_all()?;
+    Ok(())
+}
+
+pub(crate) fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    let mut buffer = [0; 4];
+    buffer.copy_from_slice(&bytes[at..at + 4]);
+    u32::from_le_bytes(buffer)
+}
+
+pub(crate) fn read_u64(bytes: &[u8], at: usize) -> u64 {
+    let mut buffer = [0; 8];
+    buffer.copy_from_slice(&bytes[at..at + 8]);
+    u64::from_le_bytes(buffer)
+}
+
+pub(crate) fn narrow(value: usize, what: &'static str) -> Result<u32, IndexError> {
+    u32::try_from(value).map_err(|_| IndexError::Overflow(what))
+}
+
+#[derive(Default)]
+struct Blob {
+    bytes: String,
+}
+
+impl Blob {
+    fn push(&mut self, text: &str) -> Result<(u32, u32), IndexError> {
+        let offset = narrow(self.bytes.len(), "string bytes")?;
+        self.bytes.push_str(text);
+        narrow(self.bytes.len(), "string bytes")?;
+        Ok((offset, text.len() as u32))
+    }
+}
+
+impl Index {
+    pub fn encode(&self) -> Result<Vec<u8>, IndexError> {
+        let entries = self.get_entries();
+        let postings = self.get_postings();
+        let metadata = self.get_all_metadata();
+
+        let mut blob = Blob::default();
+        let identifiers: HashMap<&str, u32> = postings.keys()
+            .enumerate()
+            .map(|(id, gram)| (gram.as_str(), id as u32))
+            .collect();
+
+        let mut entry_section = Vec::with_capacity(entries.len() * ENTRY);
+        let mut reference_section = Vec::new();
+        let mut references = 0usize;
+
+        for entry in entries {
+            let text = blob.push(&entry.text)?;
+            let normalized = if entry.normalized == entry.text { text } else { blob.push(&entry.normalized)? };
+
+            let start = narrow(references, "gram references")?;
+            for gram in &entry.grams {
+                let id = identifiers.get(gram.as_str()).ok_or(IndexError::Corrupt("entry gram missing from postings"))?;
+                reference_section.extend_from_slice(&id.to_le_bytes());
+                references += 1;
+            }
+            let count = narrow(entry.grams.len(), "gram references")?;
+
+            for value in [text.0, text.1, normalized.0, normalized.1, start, count] {
+                entry_section.extend_from_slice(&value.to_le_bytes());
+            }
+            entry_section.extend_from_slice(&entry.weight.to_le_bytes());
+        }
+
+        let mut gram_section = Vec::with_capacity(postings.len() * GRAM);
+        let mut posting_section = Vec::new();
+        let mut posted = 0usize;
+
+        for (gram, ids) in postings {
+            let text = blob.push(gram)?;
+            let start = narrow(posted, "postings")?;
+            for id in ids {
+                posting_section.extend_from_slice(&id.to_le_bytes());
+            }
+            posted += ids.len();
+            let count = narrow(ids.len(), "postings")?;
+
+            for value in [text.0, text.1, start, count] {
+                gram_section.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        let mut metadata_section = Vec::with_capacity(metadata.len() * METADATA);
+        for (key, value) in metadata {
+            let key = blob.push(key)?;
+            let value = blob.push(value)?;
+            for value in [key.0, key.1, value.0, value.1] {
+                metadata_section.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        let counts = [
+            narrow(self.get_size(), "gram size")?,
+            narrow(entries.len(), "entries")?,
+            narrow(references, "gram references")?,
+            narrow(postings.len(), "grams")?,
+            narrow(posted, "postings")?,
+            narrow(metadata.len(), "metadata pairs")?,
+            narrow(blob.bytes.len(), "string bytes")?,
+        ];
+
+        let mut bytes = Vec::with_capacity(
+            HEADER + entry_section.len() + reference_section.len() + gram_section.len()
+                + posting_section.len() + metadata_section.len() + blob.bytes.len(),
+        );
+
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        for count in counts {
+            bytes.extend_from_slice(&count.to_le_bytes());
+        }
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend_from_slice(&entry_section);
+        bytes.extend_from_slice(&reference_section);
+        bytes.extend_from_slice(&gram_section);
+        bytes.extend_from_slice(&posting_section);
+        bytes.extend_from_slice(&metadata_section);
+        bytes.extend_from_slice(blob.bytes.as_bytes());
+
+        seal(&mut bytes);
+        Ok(bytes)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), IndexError> {
+        write_file(path, &self.encode()?)
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, IndexError> {
+        Ok(View::parse(bytes)?.to_index())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, IndexError> {
+        let bytes = std::fs::read(path)?;
+        Self::decode(&bytes)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Section {
+    start: usize,
+    count: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Layout {
+    size: usize,
+    entries: Section,
+    references: Section,
+    grams: Section,
+    postings: Section,
+    metadata: Section,
+    blob: Section,
+}
+
+impl Layout {
+    fn read(bytes: &[u8]) -> Result<Self, IndexError> {
+        read_header(bytes, MAGIC, VERSION)?;
+
+        let count = |position: usize| read_u32(bytes, 12 + position * 4) as usize;
+        let mut cursor = HEADER;
+        let mut section = |count: usize, width: usize| {
+            let start = cursor;
+            cursor = cursor.saturating_add(count.saturating_mul(width));
+            Section { start, count }
+        };
+
+        if count(0) == 0 {
+            return Err(IndexError::Corrupt("gram size"));
+        }
+
+        let layout = Layout {
+            size: count(0),
+            entries: section(count(1), ENTRY),
+            references: section(count(2), REFERENCE),
+            grams: section(count(3), GRAM),
+            postings: section(count(4), POSTING),
+            metadata: section(count(5), METADATA),
+            blob: section(count(6), 1),
+        };
+
+        if cursor != bytes.len() {
+            return Err(IndexError::Length { found: bytes.len(), expected: cursor });
+        }
+
+        Ok(layout)
+    }
+}
+
+/// Zero-copy, validated view over an encoded index.
+#[derive(Clone, Copy)]
+pub struct View<'view> {
+    bytes: &'view [u8],
+    blob: &'view str,
+    layout: Layout,
+}
+
+impl Debug for View<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("View")
+            .field("size", &self.layout.size)
+            .field("entries", &self.layout.entries.count)
+            .field("grams", &self.layout.grams.count)
+            .finish()
+    }
+}
+
+impl<'view> View<'view> {
+    pub fn parse(bytes: &'view [u8]) -> Result<Self, IndexError> {
+        Self::read(bytes, true)
+    }
+
+    /// Checks the layout, every bound and the blob's UTF-8, and the checksum only if asked to.
+    fn read(bytes: &'view [u8], checksum: bool) -> Result<Self, IndexError> {
+        let layout = Layout::read(bytes)?;
+        if checksum {
+            verify(bytes)?;
+        }
+
+        let blob = core::str::from_utf8(&bytes[layout.blob.start..])
+            .map_err(|_| IndexError::Corrupt("string blob is not valid UTF-8"))?;
+
+        let view = Self { bytes, blob, layout };
+        view.validate()?;
+        Ok(view)
+    }
+
+    fn validate(&self) -> Result<(), IndexError> {
+        let text = |(offset, length): (usize, usize)| {
+            offset.checked_add(length).and_then(|end| self.blob.get(offset..end)).is_some()
+        };
+        let list = |(start, length): (usize, usize), section: Section| {
+            start.checked_add(length).is_some_and(|end| end <= section.count)
+        };
+
+        for id in 0..self.layout.entries.count {
+            let at = self.layout.entries.start + id * ENTRY;
+            if !text(self.span(at)) || !text(self.span(at + 8)) {
+                return Err(IndexError::Corrupt("entry text out of bounds"));
+            }
+            if !list(self.span(at + 16), self.layout.references) {
+                return Err(IndexError::Corrupt("entry references out of bounds"));
+            }
+        }
+
+        for reference in 0..self.layout.references.count {
+            if self.element(self.layout.references, reference) >= self.layout.grams.count {
+                return Err(IndexError::Corrupt("gram reference out of bounds"));
+            }
+        }
+
+        let mut previous: Option<&str> = None;
+        for id in 0..self.layout.grams.count {
+            let at = self.layout.grams.start + id * GRAM;
+            if !text(self.span(at)) {
+                return Err(IndexError::Corrupt("gram text out of bounds"));
+            }
+            if !list(self.span(at + 8), self.layout.postings) {
+                return Err(IndexError::Corrupt("gram postings out of bounds"));
+            }
+
+            let gram = self.gram(id);
+            if previous.is_some_and(|previous| previous >= gram) {
+                return Err(IndexError::Corrupt("grams are not sorted"));
+            }
+            previous = Some(gram);
+        }
+
+        for posting in 0..self.layout.postings.count {
+            if self.element(self.layout.postings, posting) >= self.layout.entries.count {
+                return Err(IndexError::Corrupt("posting out of bounds"));
+            }
+        }
+
+        for pair in 0..self.layout.metadata.count {
+            let at = self.layout.metadata.start + pair * METADATA;
+            if !text(self.span(at)) || !text(self.span(at + 8)) {
+                return Err(IndexError::Corrupt("metadata text out of bounds"));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn span(&self, at: usize) -> (usize, usize) {
+        (read_u32(self.bytes, at) as usize, read_u32(self.bytes, at + 4) as usize)
+    }
+
+    fn string(&self, at: usize) -> &'view str {
+        let (offset, length) = self.span(at);
+        &self.blob[offset..offset + length]
+    }
+
+    fn element(&self, section: Section, position: usize) -> usize {
+        read_u32(self.bytes, section.start + position * 4) as usize
+    }
+
+    fn gram(&self, id: usize) -> &'view str {
+        self.string(self.layout.grams.start + id * GRAM)
+    }
+
+    fn find(&self, gram: &str) -> Option<usize> {
+        let (mut low, mut high) = (0, self.layout.grams.count);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.gram(middle).cmp(gram) {
+                core::cmp::Ordering::Less => low = middle + 1,
+                core::cmp::Ordering::Greater => high = middle,
+                core::cmp::Ordering::Equal => return Some(middle),
+            }
+        }
+        None
+    }
+
+    pub fn size(&self) -> usize {
+        self.layout.size
+    }
+
+    pub fn len(&self) -> usize {
+        self.layout.entries.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layout.entries.count == 0
+    }
+
+    /// Offset of an entry record, or `None` past the last entry.
+    fn entry(&self, id: usize) -> Option<usize> {
+        (id < self.layout.entries.count).then(|| self.layout.entries.start + id * ENTRY)
+    }
+
+    pub fn text(&self, id: usize) -> Option<&'view str> {
+        self.entry(id).map(|at| self.string(at))
+    }
+
+    pub fn normalized(&self, id: usize) -> Option<&'view str> {
+        self.entry(id).map(|at| self.string(at + 8))
+    }
+
+    pub fn weight(&self, id: usize) -> Option<f64> {
+        self.entry(id).map(|at| f64::from_bits(read_u64(self.bytes, at + 24)))
+    }
+
+    pub fn grams(&self, id: usize) -> Option<impl Iterator<Item = &'view str> + 'view> {
+        let view = *self;
+        let (start, length) = view.span(view.entry(id)? + 16);
+        Some((start..start + length).map(move |reference| view.gram(view.element(view.layout.references, reference))))
+    }
+
+    pub fn postings(&self, gram: &str) -> impl Iterator<Item = usize> + 'view {
+        let view = *self;
+        let range = match view.find(gram) {
+            Some(id) => {
+                let (start, length) = view.span(view.layout.grams.start + id * GRAM + 8);
+                start..start + length
+            }
+            None => 0..0,
+        };
+        range.map(move |posting| view.element(view.layout.postings, posting))
+    }
+
+    pub fn get_metadata(&self, key: &str) -> Option<&'view str> {
+        (0..self.layout.metadata.count)
+            .map(|pair| self.layout.metadata.start + pair * METADATA)
+            .find(|&at| self.string(at) == key)
+            .map(|at| self.string(at + 8))
+    }
+
+    /// Entry ids sharing at least one n-gram with the query, most shared first.
+    pub fn candidates(&self, query: &str) -> Vec<usize> {
+        let grams = ngrams(&normalize(query), self.layout.size);
+        rank(grams.iter().flat_map(|gram| self.postings(gram)))
+    }
+
+    pub fn to_index(&self) -> Index {
+        let entries = (0..self.len())
+            .filter_map(|id| {
+                Some(Entry {
+                    text: self.text(id)?.to_string(),
+                    normalized: self.normalized(id)?.to_string(),
+                    grams: self.grams(id)?.map(String::from).collect(),
+                    weight: self.weight(id)?,
+                })
+            })
+            .collect();
+
+        let postings: BTreeMap<String, Vec<u32>> = (0..self.layout.grams.count)
+            .map(|id| {
+                let gram = self.gram(id);
+                (gram.to_string(), self.postings(gram).map(|entry| entry as u32).collect())
+            })
+            .collect();
+
+        let metadata = (0..self.layout.metadata.count)
+            .map(|pair| self.layout.metadata.start + pair * METADATA)
+            .map(|at| (self.string(at).to_string(), self.string(at + 8).to_string()))
+            .collect();
+
+        Index::restore(self.layout.size, entries, postings, metadata)
+    }
+}
+
+/// Memory-mapped index file, checked once on open.
+///
+/// Checking reads the whole file, so opening takes time linear in its size; views and lookups
+/// afterwards touch only the pages they need.
+pub struct Mapped {
+    map: Mmap,
+    layout: Layout,
+}
+
+impl Debug for Mapped {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Mapped").field("bytes", &self.map.len()).finish()
+    }
+}
+
+impl Mapped {
+    /// Maps a file after validating its bounds and UTF-8 and verifying its checksum.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, IndexError> {
+        Self::map(path, true)
+    }
+
+    /// Maps a file already verified once, skipping the checksum, the costliest pass. Bounds and
+    /// UTF-8 are still validated, so a corrupted file can give wrong answers but never unsound reads.
+    pub fn open_trusted<P: AsRef<Path>>(path: P) -> Result<Self, IndexError> {
+        Self::map(path, false)
+    }
+
+    fn map<P: AsRef<Path>>(path: P, checksum: bool) -> Result<Self, IndexError> {
+        let file = File::open(path)?;
+        // SAFETY: the mapping is read-only; callers must not truncate or rewrite
+        // the file while it is mapped, as with any memory-mapped file.
+        let map = unsafe { Mmap::map(&file)? };
+        let layout = View::read(&map, checksum)?.layout;
+        Ok(Self { map, layout })
+    }
+
+    pub fn view(&self) -> View<'_> {
+        let blob = &self.map[self.layout.blob.start..];
+        View {
+            bytes: &self.map,
+            // SAFETY: `open` validated the blob as UTF-8 and the mapping is read-only.
+            blob: unsafe { core::str::from_utf8_unchecked(blob) },
+            layout: self.layout,
+        }
+    }
+}
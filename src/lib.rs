@@ -1,4 +1,8 @@
 mod assessor;
+mod diff;
+mod index;
 mod prelude;
 pub use prelude::*;
-pub use assessor::*;
\ No newline at end of file
+pub use assessor::*;
+pub use diff::*;
+pub use index::*;
@@ -0,0 +1,89 @@
+/// Street types with their common abbreviations, after the USPS suffix table.
+pub const STREET_TYPES: &[(&str, &str)] = &[
+    ("st", "street"), ("str", "street"), ("ave", "avenue"), ("av", "avenue"), ("rd", "road"),
+    ("blvd", "boulevard"), ("dr", "drive"), ("ln", "lane"), ("ct", "court"), ("pl", "place"),
+    ("sq", "square"), ("hwy", "highway"), ("pkwy", "parkway"), ("ter", "terrace"), ("cir", "circle"),
+    ("trl", "trail"), ("cres", "crescent"), ("aly", "alley"), ("expy", "expressway"), ("fwy", "freeway"),
+    ("grv", "grove"), ("hts", "heights"), ("mnr", "manor"), ("plz", "plaza"), ("pt", "point"),
+    ("rte", "route"), ("tpke", "turnpike"), ("way", "way"), ("cl", "close"), ("gdns", "gardens"),
+];
+
+/// Compass directions, as used before or after a street name.
+pub const DIRECTIONS: &[(&str, &str)] = &[
+    ("n", "north"), ("s", "south"), ("e", "east"), ("w", "west"),
+    ("ne", "northeast"), ("nw", "northwest"), ("se", "southeast"), ("sw", "southwest"),
+];
+
+/// Words introducing a unit within a building, with their full forms.
+pub const UNITS: &[(&str, &str)] = &[
+    ("apt", "apartment"), ("apartment", "apartment"), ("#", "number"), ("no", "number"),
+    ("ste", "suite"), ("suite", "suite"), ("unit", "unit"), ("fl", "floor"), ("floor", "floor"),
+    ("rm", "room"), ("room", "room"), ("bldg", "building"), ("building", "building"), ("flat", "flat"),
+    ("dept", "department"), ("lot", "lot"), ("spc", "space"), ("trlr", "trailer"),
+];
+
+/// Country names and aliases with their ISO 3166 alpha-2 codes. Bare two-letter codes are left
+/// out where they would clash with a US state, such as `CA` for California.
+pub const COUNTRIES: &[(&str, &str)] = &[
+    ("us", "us"), ("usa", "us"), ("united states", "us"), ("united states of america", "us"), ("america", "us"),
+    ("uk", "gb"), ("gb", "gb"), ("united kingdom", "gb"), ("great britain", "gb"), ("england", "gb"),
+    ("scotland", "gb"), ("wales", "gb"), ("northern ireland", "gb"),
+    ("canada", "ca"), ("australia", "au"), ("new zealand", "nz"), ("ireland", "ie"), ("india", "in"),
+    ("germany", "de"), ("deutschland", "de"), ("france", "fr"), ("spain", "es"), ("españa", "es"),
+    ("italy", "it"), ("italia", "it"), ("netherlands", "nl"), ("the netherlands", "nl"), ("holland", "nl"),
+    ("belgium", "be"), ("switzerland", "ch"), ("austria", "at"), ("portugal", "pt"), ("sweden", "se"),
+    ("sverige", "se"), ("norway", "no"), ("norge", "no"), ("denmark", "dk"), ("danmark", "dk"),
+    ("mexico", "mx"), ("méxico", "mx"), ("brazil", "br"), ("brasil", "br"), ("japan", "jp"), ("china", "cn"),
+];
+
+fn lookup(table: &[(&'static str, &'static str)], word: &str) -> Option<&'static str> {
+    table.iter().find(|(abbreviation, _)| *abbreviation == word).map(|(_, full)| *full)
+}
+
+/// Lowercases and strips the periods of an abbreviation such as `St.` or `U.S.A.`.
+fn bare(word: &str) -> String {
+    word.to_lowercase().replace('.', "")
+}
+
+/// Whether a word introduces a unit, such as `Apt`, `Suite` or `#`.
+pub fn unit(word: &str) -> bool {
+    lookup(UNITS, &bare(word)).is_some()
+}
+
+/// ISO 3166 alpha-2 code for a country name or alias, ignoring case and periods.
+pub fn country(name: &str) -> Option<&'static str> {
+    let name = bare(name.trim());
+    lookup(COUNTRIES, &name).or_else(|| COUNTRIES.iter().find(|(_, code)| *code == name).map(|(_, code)| *code))
+}
+
+/// Street name with abbreviations expanded and punctuation dropped, lowercased.
+///
+/// A street type expands only as the last word, before any trailing direction, so `St` is
+/// `street` at the end but `saint` in front: `St Johns Rd` gives `saint johns road`.
+pub fn street(text: &str) -> String {
+    let words: Vec<String> = text.split_whitespace()
+        .map(|word| bare(word).trim_matches(|c: char| !c.is_alphanumeric()).to_string())
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let mut last = words.len();
+    while last > 1 && lookup(DIRECTIONS, &words[last - 1]).is_some() {
+        last -= 1;
+    }
+
+    words.iter()
+        .enumerate()
+        .map(|(index, word)| {
+            if let Some(direction) = lookup(DIRECTIONS, word) {
+                direction.to_string()
+            } else if index + 1 == last {
+                lookup(STREET_TYPES, word).unwrap_or(word).to_string()
+            } else if word == "st" {
+                "saint".to_string()
+            } else {
+                word.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
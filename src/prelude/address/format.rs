@@ -0,0 +1,29 @@
+use {
+    core::fmt::{
+        Debug, Formatter
+    },
+    crate::{
+        address::*,
+        prelude::address::matcher::{Component, ComponentPart},
+    },
+};
+
+impl Debug for Postal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "are the same address")
+    }
+}
+
+impl Debug for ComponentPart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Component::Number => write!(f, "share a house number"),
+            Component::Street => write!(f, "share a street"),
+            Component::Unit => write!(f, "share a unit"),
+            Component::City => write!(f, "share a city"),
+            Component::Region => write!(f, "share a region"),
+            Component::Postal => write!(f, "share a postal code"),
+            Component::Country => write!(f, "share a country"),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{
+    assessor::{Assessor, Resembler, Resemblance, Scheme},
+    prelude::{
+        address::{
+            abbreviations::{country, street},
+            utils::Address,
+        },
+        numeric::Absolute,
+        string::{Jaro, Words},
+    },
+};
+
+/// Weight of each address component when both addresses carry it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Weights {
+    pub number: f64,
+    pub street: f64,
+    pub unit: f64,
+    pub city: f64,
+    pub region: f64,
+    pub postal: f64,
+    pub country: f64,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self { number: 0.2, street: 0.3, unit: 0.1, city: 0.15, region: 0.05, postal: 0.15, country: 0.05 }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Component {
+    Number,
+    Street,
+    Unit,
+    City,
+    Region,
+    Postal,
+    Country,
+}
+
+impl Component {
+    fn get(self, address: &Address) -> &str {
+        match self {
+            Component::Number => &address.number,
+            Component::Street => &address.street,
+            Component::Unit => &address.unit,
+            Component::City => &address.city,
+            Component::Region => &address.region,
+            Component::Postal => &address.postal,
+            Component::Country => &address.country,
+        }
+    }
+}
+
+fn jaro(first: &str, second: &str) -> f64 {
+    Jaro::default().resemblance(&first.to_lowercase(), &second.to_lowercase()).map_or(0.0, |resemblance| resemblance.to_f64())
+}
+
+/// Leading digits as a number, with whatever follows them: `12B` gives 12 and `b`.
+fn house(number: &str) -> (Option<u64>, String) {
+    let split = number.find(|c: char| !c.is_ascii_digit()).unwrap_or(number.len());
+    let suffix = number[split..].trim_start_matches(['-', ' ']).to_lowercase();
+    (number[..split].parse().ok(), suffix)
+}
+
+/// Unit value without designator punctuation or spacing, lowercased.
+fn compact(text: &str) -> String {
+    text.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+/// One address component against the same component of another address
+pub(crate) struct ComponentPart(pub(crate) Component);
+
+impl ComponentPart {
+    fn compare(&self, query: &str, candidate: &str) -> f64 {
+        match self.0 {
+            Component::Number => {
+                let (query_number, query_suffix) = house(query);
+                let (candidate_number, candidate_suffix) = house(candidate);
+                let number = match (query_number, candidate_number) {
+                    (Some(first), Some(second)) => Absolute::new(0.0).resemblance(&first, &second).map_or(0.0, |resemblance| resemblance.to_f64()),
+                    _ => jaro(query, candidate),
+                };
+                if query_suffix == candidate_suffix { number } else { number * 0.5 }
+            }
+            Component::Street => {
+                let query = street(query);
+                let candidate = street(candidate);
+                let words = Words::default().resemblance(&query, &candidate).map_or(0.0, |resemblance| resemblance.to_f64());
+                jaro(&query, &candidate).max(words)
+            }
+            Component::Unit => {
+                if compact(query) == compact(candidate) { 1.0 } else { 0.0 }
+            }
+            Component::City | Component::Region => jaro(query, candidate),
+            Component::Postal => {
+                let query = query.replace(' ', "").to_uppercase();
+                let candidate = candidate.replace(' ', "").to_uppercase();
+                let extended = |long: &str, short: &str| long.strip_prefix(short).is_some_and(|rest| rest.starts_with('-'));
+                if query == candidate {
+                    1.0
+                } else if extended(&query, &candidate) || extended(&candidate, &query) {
+                    0.9
+                } else {
+                    0.0
+                }
+            }
+            Component::Country => match (country(query), country(candidate)) {
+                (Some(first), Some(second)) => if first == second { 1.0 } else { 0.0 },
+                _ => jaro(query, candidate),
+            },
+        }
+    }
+}
+
+impl Resembler<Address, Address, ()> for ComponentPart {
+    fn resemblance(&mut self, query: &Address, candidate: &Address) -> Result<Resemblance, ()> {
+        let score = self.compare(self.0.get(query), self.0.get(candidate));
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+/// Postal addresses compared component by component: house numbers exactly, streets with
+/// abbreviations expanded, and countries by their ISO code
+#[derive(PartialEq)]
+pub struct Postal {
+    weights: Weights,
+    scheme: Scheme,
+}
+
+impl Default for Postal {
+    fn default() -> Self {
+        Self { weights: Weights::default(), scheme: Scheme::Additive }
+    }
+}
+
+impl Postal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Component weights; a component missing from either address is left out of the score.
+    pub fn weights(mut self, weights: Weights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    pub fn scheme(mut self, scheme: Scheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    fn compute_resemblance(&self, query: &Address, candidate: &Address) -> Result<f64, ()> {
+        let components = [
+            (Component::Number, self.weights.number),
+            (Component::Street, self.weights.street),
+            (Component::Unit, self.weights.unit),
+            (Component::City, self.weights.city),
+            (Component::Region, self.weights.region),
+            (Component::Postal, self.weights.postal),
+            (Component::Country, self.weights.country),
+        ];
+
+        let mut parts: Vec<(ComponentPart, f64)> = components.into_iter()
+            .filter(|(component, _)| !component.get(query).is_empty() && !component.get(candidate).is_empty())
+            .map(|(component, weight)| (ComponentPart(component), weight))
+            .collect();
+
+        let mut assessor = Assessor::new().scheme(self.scheme.clone());
+        for (part, weight) in &mut parts {
+            assessor = assessor.dimension(part, *weight);
+        }
+
+        Ok(assessor.resemblance(query, candidate)?.to_f64())
+    }
+}
+
+impl Resembler<Address, Address, ()> for Postal {
+    fn resemblance(&mut self, query: &Address, candidate: &Address) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate)?;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+impl Resembler<String, String, ()> for Postal {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(&Address::parse(query), &Address::parse(candidate))?;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
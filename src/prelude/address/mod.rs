@@ -0,0 +1,9 @@
+pub mod utils;
+
+pub mod abbreviations;
+pub mod matcher;
+mod format;
+
+pub use utils::*;
+pub use abbreviations::*;
+pub use matcher::*;
\ No newline at end of file
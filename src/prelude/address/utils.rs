@@ -0,0 +1,136 @@
+use crate::prelude::address::abbreviations::{country, unit};
+
+fn letters(token: &str, pattern: &str) -> bool {
+    token.chars().count() == pattern.chars().count()
+        && token.chars().zip(pattern.chars()).all(|(c, kind)| match kind {
+            'A' => c.is_ascii_alphabetic(),
+            '9' => c.is_ascii_digit(),
+            _ => c == kind,
+        })
+}
+
+/// Whether one token is a postal code on its own: a US ZIP or ZIP+4, a 4 to 6 digit code, or a
+/// British or Canadian code written without its space.
+fn postal_token(token: &str) -> bool {
+    let digits = token.chars().all(|c| c.is_ascii_digit());
+    (digits && (4..=6).contains(&token.len()))
+        || letters(token, "99999-9999")
+        || letters(token, "A9A9A9")
+        || (token.is_ascii() && (5..=7).contains(&token.len()) && postal_pair(&token[..token.len() - 3], &token[token.len() - 3..]))
+}
+
+/// Whether two tokens form a British (`SW1A 2AA`), Canadian (`K1A 0B1`) or Dutch (`1234 AB`) code.
+fn postal_pair(first: &str, second: &str) -> bool {
+    let outward = ["A9", "A99", "AA9", "AA99", "A9A", "AA9A"];
+    (outward.iter().any(|pattern| letters(first, pattern)) && letters(second, "9AA"))
+        || (letters(first, "A9A") && letters(second, "9A9"))
+        || (letters(first, "9999") && letters(second, "AA"))
+}
+
+/// Token range holding a postal code, preferring a two-token code.
+fn postal(tokens: &[&str]) -> Option<(usize, usize)> {
+    (0..tokens.len().saturating_sub(1))
+        .find(|&index| postal_pair(tokens[index], tokens[index + 1]))
+        .map(|index| (index, index + 2))
+        .or_else(|| tokens.iter().position(|token| postal_token(token)).map(|index| (index, index + 1)))
+}
+
+/// A postal address split into its components, each kept as written
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Address {
+    pub number: String,
+    pub street: String,
+    pub unit: String,
+    pub city: String,
+    pub region: String,
+    pub postal: String,
+    pub country: String,
+}
+
+impl Address {
+    /// Parses a comma-separated address such as `123 Main St Apt 4B, Springfield, IL 62704, USA`.
+    ///
+    /// The first line that does not open with a unit word is the street line, holding the house
+    /// number, street and any unit; a known country may close the address; any other line
+    /// opening with a unit word gives the unit, as in `123 Main St, Suite 200, Springfield`; a
+    /// postal code is found in any later line, which otherwise give the city and then the region.
+    pub fn parse(text: &str) -> Self {
+        let mut address = Self::default();
+        let mut lines: Vec<&str> = text.split([',', '\n']).map(str::trim).filter(|line| !line.is_empty()).collect();
+
+        if lines.len() > 1 && lines.last().is_some_and(|line| country(line).is_some()) {
+            address.country = lines.pop().unwrap_or_default().to_string();
+        }
+
+        let unit_line = |line: &&str| line.split_whitespace().next().is_some_and(|word| unit(word) || word.starts_with('#'));
+        let mut lines = lines.into_iter().peekable();
+        while let Some(line) = lines.next_if(unit_line) {
+            address.read_unit(&line.split_whitespace().collect::<Vec<_>>());
+        }
+        if let Some(line) = lines.next() {
+            address.read_street(line);
+        }
+
+        for line in lines {
+            let mut tokens: Vec<&str> = line.split_whitespace().collect();
+            if unit_line(&line) {
+                address.read_unit(&tokens);
+                continue;
+            }
+            if address.postal.is_empty() {
+                if let Some((start, end)) = postal(&tokens) {
+                    address.postal = tokens.drain(start..end).collect::<Vec<_>>().join(" ");
+                }
+            }
+
+            let rest = tokens.join(" ");
+            if rest.is_empty() {
+                continue;
+            }
+            if address.city.is_empty() {
+                address.city = rest;
+            } else if address.region.is_empty() {
+                address.region = rest;
+            } else {
+                address.region = format!("{} {}", address.region, rest);
+            }
+        }
+
+        address
+    }
+
+    /// Reads a unit from its designator onwards: `Apt 4B`, `Suite 200` or `#12`.
+    fn read_unit(&mut self, tokens: &[&str]) {
+        let value = match tokens {
+            [first, rest @ ..] if first.len() > 1 && first.starts_with('#') => {
+                std::iter::once(&first[1..]).chain(rest.iter().copied()).collect::<Vec<_>>().join(" ")
+            }
+            [_, rest @ ..] => rest.join(" "),
+            [] => String::new(),
+        };
+        if self.unit.is_empty() {
+            self.unit = value;
+        }
+    }
+
+    /// Splits the street line into a house number, the street and a trailing unit.
+    fn read_street(&mut self, line: &str) {
+        let mut tokens: Vec<&str> = line.split_whitespace().collect();
+
+        let numeric = |token: &&str| token.starts_with(|c: char| c.is_ascii_digit());
+
+        if tokens.first().is_some_and(numeric) {
+            self.number = tokens.remove(0).to_string();
+        }
+        if let Some(start) = tokens.iter().skip(1).position(|token| unit(token) || token.starts_with('#')) {
+            let unit = tokens.split_off(start + 1);
+            self.read_unit(&unit);
+        }
+        // Much of Europe writes the number after the street, as in `Unter den Linden 77`.
+        if self.number.is_empty() && tokens.len() > 1 && tokens.last().is_some_and(numeric) {
+            self.number = tokens.pop().unwrap_or_default().to_string();
+        }
+
+        self.street = tokens.join(" ");
+    }
+}
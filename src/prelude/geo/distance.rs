@@ -0,0 +1,63 @@
+use crate::{
+    assessor::{Resembler, Resemblance},
+    prelude::geo::utils::{GeoError, Point},
+};
+
+/// How a distance in meters turns into a score
+#[derive(Clone, Debug, PartialEq)]
+pub enum Decay {
+    /// Falls linearly from 1 at zero to disparity at `cutoff` meters.
+    Linear { cutoff: f64 },
+    /// Halves every `half` meters.
+    Exponential { half: f64 },
+    /// Scores by the first `(meters, score)` band the distance falls within, disparity beyond the last.
+    Step(Vec<(f64, f64)>),
+}
+
+impl Decay {
+    pub fn score(&self, meters: f64) -> f64 {
+        match self {
+            Decay::Linear { cutoff } => {
+                if *cutoff <= 0.0 { 0.0 } else { (1.0 - meters / cutoff).max(0.0) }
+            }
+            Decay::Exponential { half } => {
+                if *half <= 0.0 { 0.0 } else { 0.5f64.powf(meters / half) }
+            }
+            Decay::Step(bands) => bands.iter()
+                .find(|(limit, _)| meters <= *limit)
+                .map_or(0.0, |(_, score)| *score),
+        }
+    }
+}
+
+/// Haversine distance between coordinates
+#[derive(PartialEq)]
+pub struct Haversine {
+    decay: Decay,
+}
+
+impl Default for Haversine {
+    fn default() -> Self {
+        Self { decay: Decay::Linear { cutoff: 1_000.0 } }
+    }
+}
+
+impl Haversine {
+    pub fn new(decay: Decay) -> Self {
+        Self { decay }
+    }
+}
+
+impl Resembler<Point, Point, GeoError> for Haversine {
+    fn resemblance(&mut self, query: &Point, candidate: &Point) -> Result<Resemblance, GeoError> {
+        query.validate()?;
+        candidate.validate()?;
+
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.decay.score(query.haversine(candidate));
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
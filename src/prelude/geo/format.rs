@@ -0,0 +1,20 @@
+use {
+    core::fmt::{
+        Debug, Formatter
+    },
+    crate::{
+        geo::*,
+    },
+};
+
+impl Debug for Haversine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "are close on the map")
+    }
+}
+
+impl Debug for Geohash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share the same area")
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    assessor::{Resembler, Resemblance},
+    prelude::geo::utils::{geohash, validate_geohash, GeoError, Point},
+};
+
+/// Shared geohash prefix, for coarse blocking
+#[derive(PartialEq)]
+pub struct Geohash {
+    precision: usize,
+}
+
+impl Default for Geohash {
+    fn default() -> Self {
+        Self { precision: 6 }
+    }
+}
+
+impl Geohash {
+    pub fn new(precision: usize) -> Self {
+        Self { precision: precision.clamp(1, 12) }
+    }
+}
+
+impl Resembler<Point, Point, GeoError> for Geohash {
+    fn resemblance(&mut self, query: &Point, candidate: &Point) -> Result<Resemblance, GeoError> {
+        query.validate()?;
+        candidate.validate()?;
+
+        let query_hash = geohash(query, self.precision);
+        let candidate_hash = geohash(candidate, self.precision);
+
+        let common_prefix_len = query_hash.chars().zip(candidate_hash.chars())
+            .take_while(|(c1, c2)| c1 == c2)
+            .count();
+
+        Ok((common_prefix_len as f64 / self.precision as f64).into())
+    }
+}
+
+impl Resembler<String, String, GeoError> for Geohash {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, GeoError> {
+        let query = validate_geohash(query)?;
+        let candidate = validate_geohash(candidate)?;
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let common_prefix_len = query.chars().zip(candidate.chars())
+            .take(self.precision)
+            .take_while(|(c1, c2)| c1 == c2)
+            .count();
+        let compared = self.precision.min(query.len().max(candidate.len()));
+
+        Ok((common_prefix_len as f64 / compared as f64).into())
+    }
+}
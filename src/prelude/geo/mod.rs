@@ -0,0 +1,9 @@
+pub mod utils;
+
+pub mod distance;
+pub mod geohash;
+mod format;
+
+pub use utils::*;
+pub use distance::*;
+pub use geohash::*;
@@ -0,0 +1,109 @@
+/// Mean Earth radius in meters
+pub const EARTH_RADIUS: f64 = 6_371_008.8;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeoError {
+    /// Latitude outside -90..=90 degrees, or not finite.
+    Latitude(f64),
+    /// Longitude outside -180..=180 degrees, or not finite.
+    Longitude(f64),
+    /// Geohash holding a character outside the base32 alphabet.
+    Geohash(String),
+}
+
+impl core::fmt::Display for GeoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GeoError::Latitude(value) => write!(f, "latitude {} is outside -90..=90", value),
+            GeoError::Longitude(value) => write!(f, "longitude {} is outside -180..=180", value),
+            GeoError::Geohash(hash) => write!(f, "geohash {:?} is not base32", hash),
+        }
+    }
+}
+
+impl std::error::Error for GeoError {}
+
+/// A WGS84 coordinate in degrees
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Point {
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, GeoError> {
+        let point = Self { latitude, longitude };
+        point.validate()?;
+        Ok(point)
+    }
+
+    pub fn validate(&self) -> Result<(), GeoError> {
+        if !(-90.0..=90.0).contains(&self.latitude) {
+            return Err(GeoError::Latitude(self.latitude));
+        }
+        if !(-180.0..=180.0).contains(&self.longitude) {
+            return Err(GeoError::Longitude(self.longitude));
+        }
+        Ok(())
+    }
+
+    /// Great-circle distance in meters.
+    pub fn haversine(&self, other: &Point) -> f64 {
+        let latitude_delta = (other.latitude - self.latitude).to_radians();
+        let longitude_delta = (other.longitude - self.longitude).to_radians();
+
+        let a = (latitude_delta / 2.0).sin().powi(2)
+            + self.latitude.to_radians().cos() * other.latitude.to_radians().cos() * (longitude_delta / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS * a.sqrt().min(1.0).asin()
+    }
+}
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Lowercases a geohash, rejecting characters outside the base32 alphabet.
+pub fn validate_geohash(hash: &str) -> Result<String, GeoError> {
+    let lowered = hash.to_ascii_lowercase();
+    if lowered.bytes().all(|byte| BASE32.contains(&byte)) {
+        Ok(lowered)
+    } else {
+        Err(GeoError::Geohash(hash.to_string()))
+    }
+}
+
+/// Geohash of a point with `precision` characters.
+pub fn geohash(point: &Point, precision: usize) -> String {
+    let mut latitude = (-90.0, 90.0);
+    let mut longitude = (-180.0, 180.0);
+    let mut hash = String::with_capacity(precision);
+    let mut even = true;
+    let mut bits = 0;
+    let mut value = 0usize;
+
+    while hash.len() < precision {
+        let (range, coordinate) = if even {
+            (&mut longitude, point.longitude)
+        } else {
+            (&mut latitude, point.latitude)
+        };
+
+        let middle = (range.0 + range.1) / 2.0;
+        value <<= 1;
+        if coordinate >= middle {
+            value |= 1;
+            range.0 = middle;
+        } else {
+            range.1 = middle;
+        }
+
+        even = !even;
+        bits += 1;
+        if bits == 5 {
+            hash.push(BASE32[value] as char);
+            bits = 0;
+            value = 0;
+        }
+    }
+
+    hash
+}
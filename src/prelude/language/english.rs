@@ -0,0 +1,171 @@
+use crate::prelude::language::utils::{ends_with, longest, region, replace};
+
+const EXCEPTIONS: [(&str, &str); 18] = [
+    ("skis", "ski"), ("skies", "sky"), ("dying", "die"), ("lying", "lie"), ("tying", "tie"),
+    ("idly", "idl"), ("gently", "gentl"), ("ugly", "ugli"), ("early", "earli"), ("only", "onli"),
+    ("singly", "singl"), ("sky", "sky"), ("news", "news"), ("howe", "howe"), ("atlas", "atlas"),
+    ("cosmos", "cosmos"), ("bias", "bias"), ("andes", "andes"),
+];
+
+const INVARIANT: [&str; 8] = ["inning", "outing", "canning", "herring", "earring", "proceed", "exceed", "succeed"];
+
+fn vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+fn double(word: &[char]) -> bool {
+    let length = word.len();
+    length >= 2 && word[length - 1] == word[length - 2] && matches!(word[length - 1], 'b' | 'd' | 'f' | 'g' | 'm' | 'n' | 'p' | 'r' | 't')
+}
+
+/// Whether `word` ends in a short syllable.
+fn short_syllable(word: &[char]) -> bool {
+    match word.len() {
+        0 | 1 => false,
+        2 => vowel(word[0]) && !vowel(word[1]),
+        length => {
+            !vowel(word[length - 3]) && vowel(word[length - 2])
+                && !vowel(word[length - 1]) && !matches!(word[length - 1], 'w' | 'x' | 'Y')
+        }
+    }
+}
+
+fn has_vowel(word: &[char]) -> bool {
+    word.iter().any(|&c| vowel(c))
+}
+
+/// The English (Porter2) Snowball algorithm.
+pub(crate) fn stem(text: &str) -> String {
+    if text.chars().count() <= 2 {
+        return text.to_string();
+    }
+    if let Some((_, stem)) = EXCEPTIONS.iter().find(|(word, _)| *word == text) {
+        return stem.to_string();
+    }
+
+    let mut word: Vec<char> = text.strip_prefix('\'').unwrap_or(text).chars().collect();
+
+    for position in 0..word.len() {
+        if word[position] == 'y' && (position == 0 || vowel(word[position - 1])) {
+            word[position] = 'Y';
+        }
+    }
+
+    let r1 = ["gener", "commun", "arsen"].iter()
+        .find(|prefix| word.starts_with(&prefix.chars().collect::<Vec<char>>()))
+        .map(|prefix| prefix.len())
+        .unwrap_or_else(|| region(&word, 0, vowel));
+    let r2 = region(&word, r1, vowel);
+
+    if let Some((_, start)) = longest(&word, &["'", "'s", "'s'"]) {
+        word.truncate(start);
+    }
+
+    match longest(&word, &["sses", "ied", "ies", "s", "us", "ss"]) {
+        Some(("sses", start)) => replace(&mut word, start, "ss"),
+        Some(("ied" | "ies", start)) => replace(&mut word, start, if start > 1 { "i" } else { "ie" }),
+        Some(("s", start)) if start >= 2 && has_vowel(&word[..start - 1]) => word.truncate(start),
+        _ => {}
+    }
+
+    let current: String = word.iter().collect();
+    if INVARIANT.contains(&current.as_str()) {
+        return current;
+    }
+
+    match longest(&word, &["eed", "eedly", "ed", "edly", "ing", "ingly"]) {
+        Some(("eed" | "eedly", start)) if start >= r1 => replace(&mut word, start, "ee"),
+        Some(("eed" | "eedly", _)) => {}
+        Some((_, start)) if has_vowel(&word[..start]) => {
+            word.truncate(start);
+            if ends_with(&word, "at") || ends_with(&word, "bl") || ends_with(&word, "iz") {
+                word.push('e');
+            } else if double(&word) {
+                word.pop();
+            } else if short_syllable(&word) && r1 >= word.len() {
+                word.push('e');
+            }
+        }
+        _ => {}
+    }
+
+    let length = word.len();
+    if length > 2 && matches!(word[length - 1], 'y' | 'Y') && !vowel(word[length - 2]) {
+        word[length - 1] = 'i';
+    }
+
+    let step2 = [
+        "tional", "enci", "anci", "abli", "entli", "izer", "ization", "ational", "ation", "ator",
+        "alism", "aliti", "alli", "fulness", "ousli", "ousness", "iveness", "iviti", "biliti",
+        "bli", "ogi", "fulli", "lessli", "li",
+    ];
+    if let Some((suffix, start)) = longest(&word, &step2) {
+        if start >= r1 {
+            match suffix {
+                "tional" => replace(&mut word, start, "tion"),
+                "enci" => replace(&mut word, start, "ence"),
+                "anci" => replace(&mut word, start, "ance"),
+                "abli" => replace(&mut word, start, "able"),
+                "entli" => replace(&mut word, start, "ent"),
+                "izer" | "ization" => replace(&mut word, start, "ize"),
+                "ational" | "ation" | "ator" => replace(&mut word, start, "ate"),
+                "alism" | "aliti" | "alli" => replace(&mut word, start, "al"),
+                "fulness" | "fulli" => replace(&mut word, start, "ful"),
+                "ousli" | "ousness" => replace(&mut word, start, "ous"),
+                "iveness" | "iviti" => replace(&mut word, start, "ive"),
+                "biliti" | "bli" => replace(&mut word, start, "ble"),
+                "ogi" => {
+                    if start > 0 && word[start - 1] == 'l' {
+                        replace(&mut word, start, "og");
+                    }
+                }
+                "lessli" => replace(&mut word, start, "less"),
+                _ => {
+                    if start > 0 && matches!(word[start - 1], 'c' | 'd' | 'e' | 'g' | 'h' | 'k' | 'm' | 'n' | 'r' | 't') {
+                        word.truncate(start);
+                    }
+                }
+            }
+        }
+    }
+
+    let step3 = ["tional", "ational", "alize", "icate", "iciti", "ative", "ical", "ful", "ness"];
+    if let Some((suffix, start)) = longest(&word, &step3) {
+        if start >= r1 {
+            match suffix {
+                "tional" => replace(&mut word, start, "tion"),
+                "ational" => replace(&mut word, start, "ate"),
+                "alize" => replace(&mut word, start, "al"),
+                "icate" | "iciti" | "ical" => replace(&mut word, start, "ic"),
+                "ative" => {
+                    if start >= r2 {
+                        word.truncate(start);
+                    }
+                }
+                _ => word.truncate(start),
+            }
+        }
+    }
+
+    let step4 = [
+        "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent",
+        "ism", "ate", "iti", "ous", "ive", "ize", "ion",
+    ];
+    if let Some((suffix, start)) = longest(&word, &step4) {
+        if start >= r2 && (suffix != "ion" || (start > 0 && matches!(word[start - 1], 's' | 't'))) {
+            word.truncate(start);
+        }
+    }
+
+    let length = word.len();
+    if ends_with(&word, "e") {
+        let before = &word[..length - 1];
+        if length > r2 || (length > r1 && !short_syllable(before)) {
+            word.pop();
+        }
+    } else if ends_with(&word, "ll") && length > r2 {
+        word.pop();
+    }
+
+    word.into_iter().map(|c| if c == 'Y' { 'y' } else { c }).collect()
+}
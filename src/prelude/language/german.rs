@@ -0,0 +1,99 @@
+use crate::prelude::language::utils::{ends_with, longest, region};
+
+fn vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'ä' | 'ö' | 'ü')
+}
+
+/// The German Snowball algorithm.
+pub(crate) fn stem(text: &str) -> String {
+    let mut word: Vec<char> = text.replace('ß', "ss").chars().collect();
+
+    for position in 1..word.len().saturating_sub(1) {
+        if vowel(word[position - 1]) && vowel(word[position + 1]) {
+            match word[position] {
+                'u' => word[position] = 'U',
+                'y' => word[position] = 'Y',
+                _ => {}
+            }
+        }
+    }
+
+    let r1 = region(&word, 0, vowel);
+    let r2 = region(&word, r1, vowel);
+    let r1 = r1.max(3).min(word.len());
+
+    if let Some((suffix, start)) = longest(&word, &["em", "ern", "er", "e", "en", "es", "s"]) {
+        if start >= r1 {
+            match suffix {
+                "s" => {
+                    if start > 0 && matches!(word[start - 1], 'b' | 'd' | 'f' | 'g' | 'h' | 'k' | 'l' | 'm' | 'n' | 'r' | 't') {
+                        word.truncate(start);
+                    }
+                }
+                "e" | "en" | "es" => {
+                    word.truncate(start);
+                    if ends_with(&word, "niss") {
+                        word.pop();
+                    }
+                }
+                _ => word.truncate(start),
+            }
+        }
+    }
+
+    if let Some((suffix, start)) = longest(&word, &["en", "er", "est", "st"]) {
+        if start >= r1 {
+            if suffix == "st" {
+                if start > 3 && matches!(word[start - 1], 'b' | 'd' | 'f' | 'g' | 'h' | 'k' | 'l' | 'm' | 'n' | 't') {
+                    word.truncate(start);
+                }
+            } else {
+                word.truncate(start);
+            }
+        }
+    }
+
+    if let Some((suffix, start)) = longest(&word, &["end", "ung", "ig", "ik", "isch", "lich", "heit", "keit"]) {
+        if start >= r2 {
+            let preceded = |ending: &str| ends_with(&word[..start], ending);
+            match suffix {
+                "end" | "ung" => {
+                    word.truncate(start);
+                    if ends_with(&word, "ig") && word.len() - 2 >= r2 && !ends_with(&word[..word.len() - 2], "e") {
+                        word.truncate(word.len() - 2);
+                    }
+                }
+                "ig" | "ik" | "isch" => {
+                    if !preceded("e") {
+                        word.truncate(start);
+                    }
+                }
+                "lich" | "heit" => {
+                    word.truncate(start);
+                    if (ends_with(&word, "er") || ends_with(&word, "en")) && word.len() - 2 >= r1 {
+                        word.truncate(word.len() - 2);
+                    }
+                }
+                _ => {
+                    word.truncate(start);
+                    if ends_with(&word, "lich") && word.len() - 4 >= r2 {
+                        word.truncate(word.len() - 4);
+                    } else if ends_with(&word, "ig") && word.len() - 2 >= r2 {
+                        word.truncate(word.len() - 2);
+                    }
+                }
+            }
+        }
+    }
+
+    word.into_iter()
+        .map(|c| match c {
+            'U' => 'u',
+            'Y' => 'y',
+            'ä' => 'a',
+            'ö' => 'o',
+            'ü' => 'u',
+            c => c,
+        })
+        .collect()
+}
@@ -0,0 +1,14 @@
+pub mod utils;
+
+pub mod stemmer;
+pub mod stopwords;
+pub mod tokenizer;
+mod english;
+mod german;
+mod spanish;
+mod nordic;
+
+pub use utils::*;
+pub use stemmer::*;
+pub use stopwords::*;
+pub use tokenizer::*;
@@ -0,0 +1,125 @@
+use crate::prelude::language::utils::{ends_with, longest_within, region, replace};
+
+/// R1 for the Scandinavian algorithms, which keep at least three letters before it.
+fn first_region(word: &[char], vowel: impl Fn(char) -> bool) -> usize {
+    region(word, 0, vowel).max(3).min(word.len())
+}
+
+fn swedish_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'ä' | 'å' | 'ö')
+}
+
+fn norwegian_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'æ' | 'å' | 'ø')
+}
+
+/// The Swedish Snowball algorithm.
+pub(crate) fn swedish(text: &str) -> String {
+    let mut word: Vec<char> = text.chars().collect();
+    let r1 = first_region(&word, swedish_vowel);
+
+    let step1 = [
+        "a", "arna", "erna", "heterna", "orna", "ad", "e", "ade", "ande", "arne", "are", "aste", "en",
+        "anden", "aren", "heten", "ern", "ar", "er", "heter", "or", "as", "arnas", "ernas", "ornas",
+        "es", "ades", "andes", "ens", "arens", "hetens", "erns", "at", "andet", "het", "ast", "s",
+    ];
+    if let Some((suffix, start)) = longest_within(&word, &step1, r1) {
+        if suffix != "s" || (start > 0 && "bcdfghjklmnoprtvy".contains(word[start - 1])) {
+            word.truncate(start);
+        }
+    }
+
+    if longest_within(&word, &["dd", "gd", "nn", "dt", "gt", "kt", "tt"], r1).is_some() {
+        word.pop();
+    }
+
+    match longest_within(&word, &["lig", "ig", "els", "löst", "fullt"], r1) {
+        Some(("löst" | "fullt", _)) => {
+            word.pop();
+        }
+        Some((_, start)) => word.truncate(start),
+        None => {}
+    }
+
+    word.into_iter().collect()
+}
+
+/// The Norwegian (Bokmål) Snowball algorithm.
+pub(crate) fn norwegian(text: &str) -> String {
+    let mut word: Vec<char> = text.chars().collect();
+    let r1 = first_region(&word, norwegian_vowel);
+
+    let step1 = [
+        "a", "e", "ede", "ande", "ende", "ane", "ene", "hetene", "en", "heten", "ar", "er", "heter",
+        "as", "es", "edes", "endes", "enes", "hetenes", "ens", "hetens", "ers", "ets", "et", "het",
+        "ast", "s", "erte", "ert",
+    ];
+    match longest_within(&word, &step1, r1) {
+        Some(("s", start)) => {
+            let ending = start > 0 && "bcdfghjlmnoprtvyz".contains(word[start - 1]);
+            let k = start > 1 && word[start - 1] == 'k' && !norwegian_vowel(word[start - 2]);
+            if ending || k {
+                word.truncate(start);
+            }
+        }
+        Some(("erte" | "ert", start)) => replace(&mut word, start, "er"),
+        Some((_, start)) => word.truncate(start),
+        None => {}
+    }
+
+    if longest_within(&word, &["dt", "vt"], r1).is_some() {
+        word.pop();
+    }
+
+    let step3 = ["leg", "eleg", "ig", "eig", "lig", "elig", "els", "lov", "elov", "slov", "hetslov"];
+    if let Some((_, start)) = longest_within(&word, &step3, r1) {
+        word.truncate(start);
+    }
+
+    word.into_iter().collect()
+}
+
+/// The Danish Snowball algorithm.
+pub(crate) fn danish(text: &str) -> String {
+    let mut word: Vec<char> = text.chars().collect();
+    let r1 = first_region(&word, norwegian_vowel);
+
+    let step1 = [
+        "hed", "ethed", "ered", "e", "erede", "ende", "erende", "ene", "erne", "ere", "en", "heden",
+        "eren", "er", "heder", "erer", "heds", "es", "endes", "erendes", "enes", "ernes", "eres",
+        "ens", "hedens", "erens", "ers", "ets", "erets", "et", "eret", "s",
+    ];
+    if let Some((suffix, start)) = longest_within(&word, &step1, r1) {
+        if suffix != "s" || (start > 0 && "abcdfghjklmnoprtvyzå".contains(word[start - 1])) {
+            word.truncate(start);
+        }
+    }
+
+    let consonant_pair = |word: &mut Vec<char>| {
+        if longest_within(word, &["gd", "dt", "gt", "kt"], r1).is_some() {
+            word.pop();
+        }
+    };
+    consonant_pair(&mut word);
+
+    if ends_with(&word, "igst") {
+        word.truncate(word.len() - 2);
+    }
+    match longest_within(&word, &["ig", "lig", "elig", "els", "løst"], r1) {
+        Some(("løst", _)) => {
+            word.pop();
+        }
+        Some((_, start)) => {
+            word.truncate(start);
+            consonant_pair(&mut word);
+        }
+        None => {}
+    }
+
+    let length = word.len();
+    if length >= 2 && length > r1 && word[length - 1] == word[length - 2] && !norwegian_vowel(word[length - 1]) {
+        word.pop();
+    }
+
+    word.into_iter().collect()
+}
@@ -0,0 +1,166 @@
+use crate::prelude::language::utils::{ends_with, longest, longest_within, region, replace};
+
+const PRONOUNS: [&str; 13] = ["me", "se", "sela", "selo", "selas", "selos", "la", "le", "lo", "las", "les", "los", "nos"];
+
+const STANDARD: [&str; 46] = [
+    "anza", "anzas", "ico", "ica", "icos", "icas", "ismo", "ismos", "able", "ables", "ible", "ibles",
+    "ista", "istas", "oso", "osa", "osos", "osas", "amiento", "amientos", "imiento", "imientos",
+    "adora", "ador", "ación", "adoras", "adores", "aciones", "ante", "antes", "ancia", "ancias",
+    "logía", "logías", "ución", "uciones", "encia", "encias", "amente", "mente", "idad", "idades",
+    "iva", "ivo", "ivas", "ivos",
+];
+
+const Y_VERB: [&str; 12] = ["ya", "ye", "yan", "yen", "yeron", "yendo", "yo", "yó", "yas", "yes", "yais", "yamos"];
+
+const VERB: [&str; 96] = [
+    "en", "es", "éis", "emos",
+    "arían", "arías", "arán", "arás", "aríais", "aría", "aréis", "aríamos", "aremos", "ará", "aré",
+    "erían", "erías", "erán", "erás", "eríais", "ería", "eréis", "eríamos", "eremos", "erá", "eré",
+    "irían", "irías", "irán", "irás", "iríais", "iría", "iréis", "iríamos", "iremos", "irá", "iré",
+    "aba", "ada", "ida", "ía", "ara", "iera", "ad", "ed", "id", "ase", "iese", "aste", "iste", "an",
+    "aban", "ían", "aran", "ieran", "asen", "iesen", "aron", "ieron", "ado", "ido", "ando", "iendo",
+    "ió", "ar", "er", "ir", "as", "abas", "adas", "idas", "ías", "aras", "ieras", "ases", "ieses",
+    "ís", "áis", "abais", "íais", "arais", "ierais", "aseis", "ieseis", "asteis", "isteis", "ados",
+    "idos", "amos", "ábamos", "íamos", "imos", "áramos", "iéramos", "iésemos", "ásemos",
+];
+
+fn vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'í' | 'ó' | 'ú' | 'ü')
+}
+
+fn unaccent(c: char) -> char {
+    match c {
+        'á' => 'a',
+        'é' => 'e',
+        'í' => 'i',
+        'ó' => 'o',
+        'ú' => 'u',
+        c => c,
+    }
+}
+
+/// Start of RV: after the next vowel when the second letter is a consonant, after the next
+/// consonant when the first two are vowels, and after the third letter otherwise.
+fn vowel_region(word: &[char]) -> usize {
+    if word.len() < 2 {
+        return word.len();
+    }
+
+    let after = |found: Option<usize>| found.map_or(word.len(), |position| position + 1);
+    if !vowel(word[1]) {
+        after((2..word.len()).find(|&position| vowel(word[position])))
+    } else if vowel(word[0]) {
+        after((2..word.len()).find(|&position| !vowel(word[position])))
+    } else {
+        3.min(word.len())
+    }
+}
+
+/// Removes `ending` from the end of the word when it starts at or after `limit`.
+fn strip(word: &mut Vec<char>, ending: &str, limit: usize) -> bool {
+    let length = ending.chars().count();
+    if ends_with(word, ending) && word.len() - length >= limit {
+        word.truncate(word.len() - length);
+        true
+    } else {
+        false
+    }
+}
+
+fn standard(word: &mut Vec<char>, r1: usize, r2: usize) -> bool {
+    let (suffix, start) = match longest(word, &STANDARD) {
+        Some(found) => found,
+        None => return false,
+    };
+
+    match suffix {
+        "amente" => {
+            if start < r1 {
+                return false;
+            }
+            word.truncate(start);
+            if strip(word, "iv", r2) {
+                strip(word, "at", r2);
+            } else if !strip(word, "os", r2) && !strip(word, "ic", r2) {
+                strip(word, "ad", r2);
+            }
+        }
+        _ if start < r2 => return false,
+        "adora" | "ador" | "ación" | "adoras" | "adores" | "aciones" | "ante" | "antes" | "ancia" | "ancias" => {
+            word.truncate(start);
+            strip(word, "ic", r2);
+        }
+        "logía" | "logías" => replace(word, start, "log"),
+        "ución" | "uciones" => replace(word, start, "u"),
+        "encia" | "encias" => replace(word, start, "ente"),
+        "mente" => {
+            word.truncate(start);
+            if !strip(word, "ante", r2) && !strip(word, "able", r2) {
+                strip(word, "ible", r2);
+            }
+        }
+        "idad" | "idades" => {
+            word.truncate(start);
+            if !strip(word, "abil", r2) && !strip(word, "ic", r2) {
+                strip(word, "iv", r2);
+            }
+        }
+        "iva" | "ivo" | "ivas" | "ivos" => {
+            word.truncate(start);
+            strip(word, "at", r2);
+        }
+        _ => word.truncate(start),
+    }
+
+    true
+}
+
+/// The Spanish Snowball algorithm.
+pub(crate) fn stem(text: &str) -> String {
+    let mut word: Vec<char> = text.chars().collect();
+    let rv = vowel_region(&word);
+    let r1 = region(&word, 0, vowel);
+    let r2 = region(&word, r1, vowel);
+
+    if let Some((_, start)) = longest(&word, &PRONOUNS) {
+        let endings = ["iéndo", "ándo", "ár", "ér", "ír", "ando", "iendo", "ar", "er", "ir", "yendo"];
+        if let Some((ending, ending_start)) = longest(&word[..start], &endings) {
+            if ending_start >= rv && (ending != "yendo" || (ending_start > 0 && word[ending_start - 1] == 'u')) {
+                word.truncate(start);
+                for c in &mut word[ending_start..] {
+                    *c = unaccent(*c);
+                }
+            }
+        }
+    }
+
+    if !standard(&mut word, r1, r2) {
+        let removed = match longest_within(&word, &Y_VERB, rv) {
+            Some((_, start)) if start > 0 && word[start - 1] == 'u' => {
+                word.truncate(start);
+                true
+            }
+            _ => false,
+        };
+
+        if !removed {
+            if let Some((suffix, start)) = longest_within(&word, &VERB, rv) {
+                word.truncate(start);
+                if matches!(suffix, "en" | "es" | "éis" | "emos") && ends_with(&word, "gu") {
+                    word.pop();
+                }
+            }
+        }
+    }
+
+    if let Some((suffix, start)) = longest(&word, &["os", "a", "o", "á", "í", "ó", "e", "é"]) {
+        if start >= rv {
+            word.truncate(start);
+            if matches!(suffix, "e" | "é") && ends_with(&word, "gu") && word.len() > rv {
+                word.pop();
+            }
+        }
+    }
+
+    word.into_iter().map(unaccent).collect()
+}
@@ -0,0 +1,113 @@
+use {
+    hashish::HashMap,
+    crate::prelude::language::{
+        english, german, nordic, spanish,
+        utils::Language,
+    },
+};
+
+/// Reduces an inflected word to the form it is compared by
+pub trait Stemmer: Send + Sync {
+    fn stem(&self, word: &str) -> String;
+
+    /// Names the stemmer and its settings, so two that stem differently never share an id.
+    fn id(&self) -> String {
+        core::any::type_name::<Self>().to_string()
+    }
+}
+
+/// Closures are known by their type name, which closures written in the same function share;
+/// wrap one in a named type when several must be told apart.
+impl<F: Fn(&str) -> String + Send + Sync> Stemmer for F {
+    fn stem(&self, word: &str) -> String {
+        self(word)
+    }
+}
+
+/// The English Porter2 stemmer
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Porter2;
+
+impl Stemmer for Porter2 {
+    fn stem(&self, word: &str) -> String {
+        english::stem(word)
+    }
+}
+
+/// Snowball stemmer for a bundled language; expects lowercase words
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Snowball {
+    language: Language,
+}
+
+impl Snowball {
+    pub fn new(language: Language) -> Self {
+        Self { language }
+    }
+
+    pub fn get_language(&self) -> Language {
+        self.language
+    }
+}
+
+impl Stemmer for Snowball {
+    fn stem(&self, word: &str) -> String {
+        match self.language {
+            Language::English => english::stem(word),
+            Language::German => german::stem(word),
+            Language::Spanish => spanish::stem(word),
+            Language::Swedish => nordic::swedish(word),
+            Language::Norwegian => nordic::norwegian(word),
+            Language::Danish => nordic::danish(word),
+        }
+    }
+
+    fn id(&self) -> String {
+        format!("snowball:{}", self.language.code())
+    }
+}
+
+/// Lemma lookup table, deferring unknown words to an optional fallback stemmer
+#[derive(Default)]
+pub struct Lemmatizer {
+    lemmas: HashMap<String, String>,
+    fallback: Option<Box<dyn Stemmer>>,
+}
+
+impl Lemmatizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn lemma(mut self, form: &str, lemma: &str) -> Self {
+        self.insert(form, lemma);
+        self
+    }
+
+    pub fn fallback<S: Stemmer + 'static>(mut self, stemmer: S) -> Self {
+        self.fallback = Some(Box::new(stemmer));
+        self
+    }
+
+    pub fn insert(&mut self, form: &str, lemma: &str) {
+        self.lemmas.insert(form.to_string(), lemma.to_string());
+    }
+}
+
+impl Stemmer for Lemmatizer {
+    fn stem(&self, word: &str) -> String {
+        match (self.lemmas.get(word), &self.fallback) {
+            (Some(lemma), _) => lemma.clone(),
+            (None, Some(fallback)) => fallback.stem(word),
+            (None, None) => word.to_string(),
+        }
+    }
+
+    fn id(&self) -> String {
+        let mut lemmas: Vec<(&String, &String)> = self.lemmas.iter().collect();
+        lemmas.sort();
+
+        let fallback = self.fallback.as_ref().map(|fallback| fallback.id());
+        format!("lemmatizer{:?}{:?}", lemmas, fallback)
+    }
+}
@@ -0,0 +1,150 @@
+use crate::prelude::language::utils::Language;
+
+const ENGLISH: &[&str] = &[
+    "a", "about", "above", "after", "again", "against", "all", "am", "an", "and", "any", "are", "as",
+    "at", "be", "because", "been", "before", "being", "below", "between", "both", "but", "by", "can",
+    "could", "did", "do", "does", "doing", "down", "during", "each", "few", "for", "from", "further",
+    "had", "has", "have", "having", "he", "her", "here", "hers", "herself", "him", "himself", "his",
+    "how", "i", "if", "in", "into", "is", "it", "its", "itself", "just", "me", "more", "most", "my",
+    "myself", "no", "nor", "not", "now", "of", "off", "on", "once", "only", "or", "other", "ought",
+    "our", "ours", "ourselves", "out", "over", "own", "same", "she", "should", "so", "some", "such",
+    "than", "that", "the", "their", "theirs", "them", "themselves", "then", "there", "these", "they",
+    "this", "those", "through", "to", "too", "under", "until", "up", "very", "was", "we", "were",
+    "what", "when", "where", "which", "while", "who", "whom", "why", "will", "with", "would", "you",
+    "your", "yours", "yourself", "yourselves",
+];
+
+const GERMAN: &[&str] = &[
+    "aber", "alle", "allem", "allen", "aller", "alles", "als", "also", "am", "an", "ander", "andere",
+    "anderem", "anderen", "anderer", "anderes", "auch", "auf", "aus", "bei", "bin", "bis", "bist", "da",
+    "damit", "dann", "das", "dass", "dein", "deine", "dem", "den", "denn", "der", "des", "dich", "die",
+    "dies", "diese", "diesem", "diesen", "dieser", "dieses", "dir", "doch", "dort", "du", "durch", "ein",
+    "eine", "einem", "einen", "einer", "eines", "er", "es", "etwas", "euch", "euer", "für", "gegen",
+    "hab", "habe", "haben", "hat", "hatte", "hier", "hin", "ich", "ihm", "ihn", "ihnen", "ihr", "ihre",
+    "im", "in", "ist", "jede", "jedem", "jeden", "jeder", "jetzt", "kann", "kein", "keine", "man",
+    "mein", "meine", "mich", "mir", "mit", "muss", "nach", "nicht", "nichts", "noch", "nun", "nur", "ob",
+    "oder", "ohne", "sehr", "sein", "seine", "sich", "sie", "sind", "so", "solche", "soll", "um", "und",
+    "uns", "unser", "unter", "viel", "vom", "von", "vor", "war", "waren", "was", "weil", "welche",
+    "wenn", "wer", "wie", "wieder", "will", "wir", "wird", "wo", "zu", "zum", "zur", "zwischen",
+];
+
+const SPANISH: &[&str] = &[
+    "a", "al", "algo", "algunas", "algunos", "ante", "antes", "como", "con", "contra", "cual", "cuando",
+    "de", "del", "desde", "donde", "durante", "e", "el", "ella", "ellas", "ellos", "en", "entre", "era",
+    "es", "esa", "esas", "ese", "eso", "esos", "esta", "estaba", "estado", "estas", "este", "esto",
+    "estos", "está", "están", "fue", "fueron", "ha", "han", "hasta", "hay", "la", "las", "le", "les",
+    "lo", "los", "me", "mi", "mis", "mucho", "muy", "más", "mí", "nada", "ni", "no", "nos", "nosotros",
+    "o", "os", "otra", "otros", "para", "pero", "poco", "por", "porque", "que", "quien", "qué", "se",
+    "sea", "ser", "si", "sido", "sin", "sobre", "son", "su", "sus", "también", "te", "tiene", "todo",
+    "todos", "tu", "tus", "tú", "un", "una", "uno", "unos", "vosotros", "y", "ya", "yo", "él",
+];
+
+const FRENCH: &[&str] = &[
+    "au", "aux", "avec", "ce", "ces", "cette", "dans", "de", "des", "du", "elle", "elles", "en", "est",
+    "et", "eu", "il", "ils", "je", "la", "le", "les", "leur", "leurs", "lui", "ma", "mais", "me", "mes",
+    "moi", "mon", "même", "ne", "nos", "notre", "nous", "on", "ou", "où", "par", "pas", "pour", "qu",
+    "que", "qui", "sa", "se", "ses", "son", "sont", "sur", "ta", "te", "tes", "toi", "ton", "tu", "un",
+    "une", "vos", "votre", "vous", "c", "d", "j", "l", "m", "n", "s", "t", "y", "été", "étaient",
+    "était", "étant", "être", "avait", "avaient", "avoir", "ont", "a", "sera", "serait", "fait", "comme",
+    "si", "tout", "tous", "plus", "aussi", "cet", "ceux", "car", "donc", "ni", "or",
+];
+
+const ITALIAN: &[&str] = &[
+    "a", "ad", "al", "alla", "alle", "agli", "ai", "anche", "avere", "che", "chi", "ci", "come", "con",
+    "contro", "cui", "da", "dal", "dalla", "dalle", "degli", "dei", "del", "della", "delle", "di", "dove",
+    "e", "ed", "è", "era", "essere", "gli", "ha", "hanno", "ho", "i", "il", "in", "io", "la", "le",
+    "lei", "li", "lo", "loro", "lui", "ma", "mi", "mia", "mio", "ne", "negli", "nei", "nel", "nella",
+    "nelle", "noi", "non", "nostro", "o", "per", "perché", "più", "quale", "quando", "quella", "quello",
+    "questa", "questo", "se", "sei", "si", "sia", "siamo", "sono", "su", "sua", "sue", "sui", "sul",
+    "sulla", "suo", "tra", "tu", "tutti", "tutto", "un", "una", "uno", "vi", "voi",
+];
+
+const PORTUGUESE: &[&str] = &[
+    "a", "ao", "aos", "as", "até", "com", "como", "da", "das", "de", "dela", "dele", "deles", "depois",
+    "do", "dos", "e", "ela", "elas", "ele", "eles", "em", "entre", "era", "essa", "esse", "esta", "está",
+    "este", "eu", "foi", "for", "há", "isso", "isto", "já", "lhe", "mais", "mas", "me", "mesmo", "meu",
+    "minha", "muito", "na", "nas", "nem", "no", "nos", "nossa", "nosso", "num", "numa", "não", "nós",
+    "o", "os", "ou", "para", "pela", "pelas", "pelo", "pelos", "por", "qual", "quando", "que", "quem",
+    "se", "sem", "ser", "seu", "sua", "são", "só", "também", "te", "tem", "tu", "um", "uma", "você",
+    "à", "às", "é",
+];
+
+const DUTCH: &[&str] = &[
+    "aan", "al", "alles", "als", "altijd", "andere", "ben", "bij", "daar", "dan", "dat", "de", "der",
+    "deze", "die", "dit", "doch", "doen", "door", "dus", "een", "eens", "en", "er", "ge", "geen",
+    "geweest", "haar", "had", "heb", "hebben", "heeft", "hem", "het", "hier", "hij", "hoe", "hun",
+    "iemand", "iets", "ik", "in", "is", "ja", "je", "kan", "kon", "kunnen", "maar", "me", "meer", "men",
+    "met", "mij", "mijn", "moet", "na", "naar", "niet", "niets", "nog", "nu", "of", "om", "omdat",
+    "onder", "ons", "ook", "op", "over", "reeds", "te", "tegen", "toch", "toen", "tot", "u", "uit",
+    "uw", "van", "veel", "voor", "want", "waren", "was", "wat", "we", "wel", "werd", "wezen", "wie",
+    "wij", "wil", "worden", "zal", "ze", "zelf", "zich", "zij", "zijn", "zo", "zonder", "zou",
+];
+
+const SWEDISH: &[&str] = &[
+    "alla", "allt", "att", "av", "blev", "bli", "blir", "de", "dem", "den", "denna", "deras", "dess",
+    "det", "detta", "dig", "din", "dina", "du", "där", "då", "efter", "ej", "eller", "en", "er", "era",
+    "ett", "från", "för", "ha", "hade", "han", "hans", "har", "henne", "hennes", "hon", "honom", "hur",
+    "här", "i", "icke", "ingen", "inom", "inte", "jag", "ju", "kan", "kunde", "man", "med", "mellan",
+    "men", "mig", "min", "mina", "mitt", "mot", "mycket", "ni", "nu", "när", "någon", "något", "några",
+    "och", "om", "oss", "på", "samma", "sedan", "sig", "sin", "sina", "sitta", "själv", "skulle", "som",
+    "så", "till", "under", "upp", "ut", "utan", "vad", "var", "vara", "varför", "vart", "vem", "vi",
+    "vid", "vilka", "vilken", "är", "åt", "över",
+];
+
+const NORWEGIAN: &[&str] = &[
+    "alle", "at", "av", "bare", "begge", "ble", "blei", "bli", "blir", "da", "de", "deg", "dei", "deim",
+    "deira", "deires", "dem", "den", "denne", "der", "dere", "deres", "det", "dette", "di", "din", "disse",
+    "ditt", "du", "dykk", "eg", "ein", "eit", "eitt", "eller", "elles", "en", "enn", "er", "et", "ett",
+    "etter", "for", "fordi", "fra", "før", "ha", "hadde", "han", "hans", "har", "hennar", "henne",
+    "hennes", "her", "hjå", "ho", "hoe", "honom", "hoss", "hossen", "hun", "hva", "hvem", "hver", "hvilke",
+    "hvis", "hvor", "hvordan", "i", "ikke", "inn", "inni", "jeg", "kan", "kom", "korleis", "kun", "man",
+    "mange", "me", "med", "meg", "mellom", "men", "mi", "min", "mine", "mitt", "mot", "mye", "ned", "no",
+    "noe", "noen", "nå", "når", "og", "også", "om", "opp", "oss", "over", "på", "samme", "seg", "selv",
+    "si", "sin", "sine", "sitt", "skal", "som", "så", "til", "um", "under", "upp", "ut", "uten", "var",
+    "vart", "ved", "vi", "vil", "være", "vært", "å",
+];
+
+const DANISH: &[&str] = &[
+    "af", "alle", "andet", "andre", "at", "begge", "da", "de", "den", "denne", "der", "deres", "det",
+    "dette", "dig", "din", "dog", "du", "efter", "eller", "en", "end", "er", "et", "for", "fra", "ham",
+    "han", "hans", "har", "havde", "have", "hende", "hendes", "her", "hos", "hun", "hvad", "hvis", "hvor",
+    "i", "ikke", "ind", "jeg", "jer", "jo", "kunne", "man", "mange", "med", "meget", "men", "mig", "min",
+    "mine", "mit", "mod", "ned", "noget", "nogle", "nu", "når", "og", "også", "om", "op", "os", "over",
+    "på", "selv", "sig", "sin", "sine", "sit", "skal", "skulle", "som", "sådan", "thi", "til", "ud",
+    "under", "var", "vi", "vil", "ville", "vor", "være", "været",
+];
+
+const RUSSIAN: &[&str] = &[
+    "а", "без", "более", "бы", "был", "была", "были", "было", "быть", "в", "вам", "вас", "весь", "во",
+    "вот", "все", "всего", "всех", "вы", "где", "да", "даже", "для", "до", "его", "ее", "ей", "ему",
+    "если", "есть", "еще", "ж", "же", "за", "здесь", "и", "из", "или", "им", "их", "к", "как", "когда",
+    "кто", "ли", "либо", "мне", "может", "мы", "на", "над", "надо", "наш", "не", "него", "нее", "нет",
+    "ни", "них", "но", "ну", "о", "об", "однако", "он", "она", "они", "оно", "от", "очень", "по", "под",
+    "при", "с", "со", "так", "также", "такой", "там", "те", "тем", "то", "того", "тоже", "той", "только",
+    "том", "ты", "у", "уже", "хотя", "чего", "чей", "чем", "что", "чтобы", "эта", "эти", "это", "я",
+];
+
+/// Bundled stop words for an ISO 639-1 language code, covering `en`, `de`, `es`, `fr`, `it`,
+/// `pt`, `nl`, `sv`, `no`, `da` and `ru`.
+pub fn stop_words(code: &str) -> Option<&'static [&'static str]> {
+    match code.to_ascii_lowercase().as_str() {
+        "en" => Some(ENGLISH),
+        "de" => Some(GERMAN),
+        "es" => Some(SPANISH),
+        "fr" => Some(FRENCH),
+        "it" => Some(ITALIAN),
+        "pt" => Some(PORTUGUESE),
+        "nl" => Some(DUTCH),
+        "sv" => Some(SWEDISH),
+        "no" | "nb" | "nn" => Some(NORWEGIAN),
+        "da" => Some(DANISH),
+        "ru" => Some(RUSSIAN),
+        _ => None,
+    }
+}
+
+impl Language {
+    pub fn stop_words(&self) -> &'static [&'static str] {
+        stop_words(self.code()).unwrap_or_default()
+    }
+}
@@ -0,0 +1,232 @@
+/// Splits text into the words a resembler compares
+pub trait Tokenizer: Send + Sync {
+    fn tokenize<'text>(&self, text: &'text str) -> Vec<&'text str>;
+
+    /// Names the tokenizer and its settings, so two that split differently never share an id.
+    fn id(&self) -> String {
+        core::any::type_name::<Self>().to_string()
+    }
+}
+
+/// Collects the maximal runs of characters for which `inside`, given each character's index
+/// among the characters of `text`, holds.
+fn runs(text: &str, inside: impl Fn(usize, char) -> bool) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+
+    for (index, (position, c)) in text.char_indices().enumerate() {
+        match (inside(index, c), start) {
+            (true, None) => start = Some(position),
+            (false, Some(begin)) => {
+                tokens.push(&text[begin..position]);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        tokens.push(&text[begin..]);
+    }
+
+    tokens
+}
+
+/// Splits on whitespace and on any extra separator characters
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Whitespace {
+    separators: Vec<char>,
+}
+
+impl Whitespace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn separators(mut self, separators: Vec<char>) -> Self {
+        self.separators = separators;
+        self
+    }
+}
+
+impl Tokenizer for Whitespace {
+    fn tokenize<'text>(&self, text: &'text str) -> Vec<&'text str> {
+        runs(text, |_, c| !c.is_whitespace() && !self.separators.contains(&c))
+    }
+
+    fn id(&self) -> String {
+        let mut separators = self.separators.clone();
+        separators.sort_unstable();
+        separators.dedup();
+        format!("whitespace{:?}", separators)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Break {
+    Letter,
+    Numeric,
+    Katakana,
+    ExtendNumLet,
+    MidLetter,
+    MidNum,
+    MidNumLet,
+    Extend,
+    Other,
+}
+
+fn ideographic(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{309F}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{20000}'..='\u{2FA1F}'
+    )
+}
+
+fn classify(c: char) -> Break {
+    match c {
+        '\u{0300}'..='\u{036F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{1DC0}'..='\u{1DFF}' | '\u{20D0}'..='\u{20FF}'
+        | '\u{FE00}'..='\u{FE0F}' | '\u{FE20}'..='\u{FE2F}' | '\u{200D}' | '\u{00AD}' | '\u{2060}' | '\u{FEFF}' => Break::Extend,
+        '\u{30A0}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}' => Break::Katakana,
+        '_' | '\u{203F}' | '\u{2040}' | '\u{2054}' | '\u{FE33}' | '\u{FE34}' | '\u{FE4D}'..='\u{FE4F}' | '\u{FF3F}' => Break::ExtendNumLet,
+        ':' | '\u{00B7}' | '\u{0387}' | '\u{05F4}' | '\u{2027}' | '\u{FE13}' | '\u{FE55}' | '\u{FF1A}' => Break::MidLetter,
+        ',' | ';' | '\u{037E}' | '\u{0589}' | '\u{060C}' | '\u{060D}' | '\u{066C}' | '\u{07F8}' | '\u{2044}'
+        | '\u{FE10}' | '\u{FE14}' | '\u{FE50}' | '\u{FE54}' | '\u{FF0C}' | '\u{FF1B}' => Break::MidNum,
+        '.' | '\'' | '\u{2018}' | '\u{2019}' | '\u{2024}' | '\u{FE52}' | '\u{FF07}' | '\u{FF0E}' => Break::MidNumLet,
+        c if c.is_numeric() => Break::Numeric,
+        c if c.is_alphabetic() && !ideographic(c) => Break::Letter,
+        _ => Break::Other,
+    }
+}
+
+/// Unicode word boundaries after UAX #29, keeping only segments holding letters or digits.
+///
+/// Covers the letter, number, katakana, connector and mid-word punctuation rules; ideographs
+/// and kana become single-character words as the standard leaves them unjoined.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Unicode;
+
+impl Tokenizer for Unicode {
+    fn tokenize<'text>(&self, text: &'text str) -> Vec<&'text str> {
+        let characters: Vec<(usize, char, Break)> = text.char_indices()
+            .map(|(position, c)| (position, c, classify(c)))
+            .collect();
+
+        // Classes with extend and format characters folded into their base (WB4).
+        let mut base: Vec<Break> = Vec::with_capacity(characters.len());
+        for &(_, _, class) in &characters {
+            let folded = match (class, base.last()) {
+                (Break::Extend, Some(&previous)) => previous,
+                _ => class,
+            };
+            base.push(folded);
+        }
+
+        let following = |index: usize| {
+            (index + 1..characters.len()).map(|next| characters[next].2).find(|&class| class != Break::Extend)
+        };
+        let letter_like = |class: Break| matches!(class, Break::Letter);
+        let word_like = |class: Break| matches!(class, Break::Letter | Break::Numeric | Break::Katakana | Break::ExtendNumLet);
+
+        let mut tokens = Vec::new();
+        let mut start = 0;
+
+        for index in 1..=characters.len() {
+            let joined = index < characters.len() && {
+                let before = base[index - 1];
+                let current = characters[index].2;
+                let earlier = index.checked_sub(2).map(|previous| base[previous]);
+
+                match (before, current) {
+                    (_, Break::Extend) => true,
+                    (Break::Letter, Break::Letter) | (Break::Numeric, Break::Numeric) => true,
+                    (Break::Letter, Break::Numeric) | (Break::Numeric, Break::Letter) => true,
+                    (Break::Katakana, Break::Katakana) => true,
+                    (Break::ExtendNumLet, _) if word_like(current) => true,
+                    (_, Break::ExtendNumLet) if word_like(before) => true,
+                    (Break::Letter, Break::MidLetter | Break::MidNumLet) => following(index).is_some_and(letter_like),
+                    (Break::MidLetter | Break::MidNumLet, Break::Letter) => earlier.is_some_and(letter_like),
+                    (Break::Numeric, Break::MidNum | Break::MidNumLet) => following(index) == Some(Break::Numeric),
+                    (Break::MidNum | Break::MidNumLet, Break::Numeric) => earlier == Some(Break::Numeric),
+                    _ => false,
+                }
+            };
+
+            if !joined {
+                let end = characters.get(index).map_or(text.len(), |&(position, _, _)| position);
+                let segment = &text[characters[start].0..end];
+                if segment.chars().any(char::is_alphanumeric) {
+                    tokens.push(segment);
+                }
+                start = index;
+            }
+        }
+
+        tokens
+    }
+}
+
+/// Splits source-code identifiers on case changes, digits, underscores and other punctuation,
+/// keeping acronyms whole: `parseHTTPResponse_v2` gives `parse`, `HTTP`, `Response`, `v`, `2`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Identifier;
+
+impl Tokenizer for Identifier {
+    fn tokenize<'text>(&self, text: &'text str) -> Vec<&'text str> {
+        let characters: Vec<(usize, char)> = text.char_indices().collect();
+        let mut tokens = Vec::new();
+        let mut start: Option<usize> = None;
+
+        for (index, &(position, c)) in characters.iter().enumerate() {
+            if !c.is_alphanumeric() {
+                if let Some(begin) = start.take() {
+                    tokens.push(&text[begin..position]);
+                }
+                continue;
+            }
+
+            if let Some(begin) = start {
+                let previous = characters[index - 1].1;
+                let next = characters.get(index + 1).map(|&(_, next)| next);
+                let boundary = (previous.is_numeric() != c.is_numeric())
+                    || (previous.is_lowercase() && c.is_uppercase())
+                    || (previous.is_uppercase() && c.is_uppercase() && next.is_some_and(char::is_lowercase));
+
+                if boundary {
+                    tokens.push(&text[begin..position]);
+                    start = Some(position);
+                }
+            } else {
+                start = Some(position);
+            }
+        }
+        if let Some(begin) = start {
+            tokens.push(&text[begin..]);
+        }
+
+        tokens
+    }
+}
+
+/// Splits on whitespace and punctuation, but keeps apostrophes, hyphens, periods and `@`
+/// inside words and commas inside numbers, so `don't`, `e-mail`, `3,141.59`, `U.S` and
+/// `a@b.com` stay whole
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Punctuation;
+
+impl Tokenizer for Punctuation {
+    fn tokenize<'text>(&self, text: &'text str) -> Vec<&'text str> {
+        let characters: Vec<char> = text.chars().collect();
+
+        runs(text, |index, c| {
+            let between = |test: fn(&char) -> bool| {
+                index > 0 && test(&characters[index - 1]) && characters.get(index + 1).is_some_and(test)
+            };
+
+            c.is_alphanumeric()
+                || (matches!(c, '\'' | '\u{2019}' | '-' | '.' | '@') && between(|c| c.is_alphanumeric()))
+                || (c == ',' && between(|c| c.is_numeric()))
+        })
+    }
+}
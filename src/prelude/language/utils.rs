@@ -0,0 +1,84 @@
+use core::str::FromStr;
+
+/// Languages with bundled stemmers
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Language {
+    English,
+    German,
+    Spanish,
+    Swedish,
+    Norwegian,
+    Danish,
+}
+
+impl Language {
+    /// ISO 639-1 code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+            Language::Spanish => "es",
+            Language::Swedish => "sv",
+            Language::Norwegian => "no",
+            Language::Danish => "da",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Language> {
+        match code.to_ascii_lowercase().as_str() {
+            "en" => Some(Language::English),
+            "de" => Some(Language::German),
+            "es" => Some(Language::Spanish),
+            "sv" => Some(Language::Swedish),
+            "no" | "nb" | "nn" => Some(Language::Norwegian),
+            "da" => Some(Language::Danish),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = ();
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Language::from_code(code).ok_or(())
+    }
+}
+
+pub(crate) fn ends_with(word: &[char], suffix: &str) -> bool {
+    let suffix: Vec<char> = suffix.chars().collect();
+    word.len() >= suffix.len() && word[word.len() - suffix.len()..] == suffix[..]
+}
+
+/// Longest of `suffixes` ending the word, with the index it starts at.
+pub(crate) fn longest<'suffix>(word: &[char], suffixes: &[&'suffix str]) -> Option<(&'suffix str, usize)> {
+    suffixes.iter()
+        .filter(|suffix| ends_with(word, suffix))
+        .max_by_key(|suffix| suffix.chars().count())
+        .map(|suffix| (*suffix, word.len() - suffix.chars().count()))
+}
+
+/// Like [`longest`], but only considers suffixes starting at or after `limit`.
+pub(crate) fn longest_within<'suffix>(word: &[char], suffixes: &[&'suffix str], limit: usize) -> Option<(&'suffix str, usize)> {
+    suffixes.iter()
+        .filter(|suffix| ends_with(word, suffix) && word.len() - suffix.chars().count() >= limit)
+        .max_by_key(|suffix| suffix.chars().count())
+        .map(|suffix| (*suffix, word.len() - suffix.chars().count()))
+}
+
+pub(crate) fn replace(word: &mut Vec<char>, start: usize, replacement: &str) {
+    word.truncate(start);
+    word.extend(replacement.chars());
+}
+
+/// Start of the region after the first non-vowel following a vowel, from `from` on.
+pub(crate) fn region(word: &[char], from: usize, vowel: impl Fn(char) -> bool) -> usize {
+    let mut position = from;
+    while position < word.len() && !vowel(word[position]) {
+        position += 1;
+    }
+    while position < word.len() && vowel(word[position]) {
+        position += 1;
+    }
+    (position + 1).min(word.len())
+}
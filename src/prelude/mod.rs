@@ -1 +1,11 @@
-pub mod string;
\ No newline at end of file
+pub mod string;
+pub mod numeric;
+pub mod temporal;
+pub mod geo;
+pub mod vector;
+pub mod set;
+pub mod sequence;
+pub mod language;
+pub mod person;
+pub mod address;
+pub mod web;
\ No newline at end of file
@@ -0,0 +1,32 @@
+use {
+    core::fmt::{
+        Debug, Formatter
+    },
+    crate::{
+        numeric::*,
+    },
+};
+
+impl Debug for Absolute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "are close in value")
+    }
+}
+
+impl Debug for Relative {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "are close in proportion")
+    }
+}
+
+impl Debug for Logarithmic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "are close in magnitude")
+    }
+}
+
+impl Debug for Gaussian {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "are close on a bell curve")
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    assessor::{Resembler, Resemblance},
+    prelude::numeric::utils::{distinct, finite, Number, NumericError},
+};
+
+/// Linear decay over the log ratio, reaching disparity when one value is `ratio` times the other
+#[derive(PartialEq)]
+pub struct Logarithmic {
+    ratio: f64,
+}
+
+impl Default for Logarithmic {
+    fn default() -> Self {
+        Self { ratio: 2.0 }
+    }
+}
+
+impl Logarithmic {
+    /// An infinite ratio matches any two values, just short of perfect when they differ;
+    /// NaN is taken as one, so only equal values match.
+    pub fn new(ratio: f64) -> Self {
+        Self { ratio: if ratio.is_nan() { 1.0 } else { ratio.abs().max(1.0) } }
+    }
+}
+
+impl<N: Number> Resembler<N, N, NumericError> for Logarithmic {
+    fn resemblance(&mut self, query: &N, candidate: &N) -> Result<Resemblance, NumericError> {
+        let (query_value, candidate_value) = (finite(*query)?, finite(*candidate)?);
+
+        if query_value <= 0.0 || candidate_value <= 0.0 {
+            return Err(NumericError::NonPositive);
+        }
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+        if self.ratio == 1.0 {
+            return Ok(Resemblance::Disparity);
+        }
+        if self.ratio.is_infinite() {
+            return Ok(distinct(1.0));
+        }
+
+        let score = 1.0 - (query_value / candidate_value).ln().abs() / self.ratio.ln();
+        Ok(distinct(score))
+    }
+}
+
+/// Gaussian kernel `exp(-d² / 2σ²)` over the absolute difference
+#[derive(PartialEq)]
+pub struct Gaussian {
+    sigma: f64,
+}
+
+impl Default for Gaussian {
+    fn default() -> Self {
+        Self { sigma: 1.0 }
+    }
+}
+
+impl Gaussian {
+    /// An infinite sigma matches any two values, just short of perfect when they differ;
+    /// NaN is taken as zero, so only equal values match.
+    pub fn new(sigma: f64) -> Self {
+        Self { sigma: if sigma.is_nan() { 0.0 } else { sigma.abs() } }
+    }
+}
+
+impl<N: Number> Resembler<N, N, NumericError> for Gaussian {
+    fn resemblance(&mut self, query: &N, candidate: &N) -> Result<Resemblance, NumericError> {
+        finite(*query)?;
+        finite(*candidate)?;
+
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+        if self.sigma == 0.0 {
+            return Ok(Resemblance::Disparity);
+        }
+        if self.sigma.is_infinite() {
+            return Ok(distinct(1.0));
+        }
+
+        let distance = query.difference(*candidate);
+        let score = (-(distance * distance) / (2.0 * self.sigma * self.sigma)).exp();
+        Ok(distinct(score))
+    }
+}
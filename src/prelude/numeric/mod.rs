@@ -0,0 +1,9 @@
+pub mod utils;
+
+pub mod kernel;
+pub mod tolerance;
+mod format;
+
+pub use utils::*;
+pub use kernel::*;
+pub use tolerance::*;
@@ -0,0 +1,89 @@
+use crate::{
+    assessor::{Resembler, Resemblance},
+    prelude::numeric::utils::{distinct, finite, Number, NumericError},
+};
+
+/// Linear decay over an absolute difference, reaching disparity at `tolerance`
+#[derive(PartialEq)]
+pub struct Absolute {
+    tolerance: f64,
+}
+
+impl Default for Absolute {
+    fn default() -> Self {
+        Self { tolerance: 1.0 }
+    }
+}
+
+impl Absolute {
+    /// An infinite tolerance matches any two values, just short of perfect when they differ;
+    /// NaN is taken as zero, so only equal values match.
+    pub fn new(tolerance: f64) -> Self {
+        Self { tolerance: if tolerance.is_nan() { 0.0 } else { tolerance.abs() } }
+    }
+}
+
+impl<N: Number> Resembler<N, N, NumericError> for Absolute {
+    fn resemblance(&mut self, query: &N, candidate: &N) -> Result<Resemblance, NumericError> {
+        finite(*query)?;
+        finite(*candidate)?;
+
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+        if self.tolerance == 0.0 {
+            return Ok(Resemblance::Disparity);
+        }
+        if self.tolerance.is_infinite() {
+            return Ok(distinct(1.0));
+        }
+
+        let score = 1.0 - query.difference(*candidate) / self.tolerance;
+        Ok(distinct(score))
+    }
+}
+
+/// Linear decay over the difference relative to the larger magnitude, `tolerance` being a fraction (0.1 = 10%)
+#[derive(PartialEq)]
+pub struct Relative {
+    tolerance: f64,
+}
+
+impl Default for Relative {
+    fn default() -> Self {
+        Self { tolerance: 0.1 }
+    }
+}
+
+impl Relative {
+    /// An infinite tolerance matches any two values, just short of perfect when they differ;
+    /// NaN is taken as zero, so only equal values match.
+    pub fn new(tolerance: f64) -> Self {
+        Self { tolerance: if tolerance.is_nan() { 0.0 } else { tolerance.abs() } }
+    }
+
+    pub fn percent(percent: f64) -> Self {
+        Self::new(percent / 100.0)
+    }
+}
+
+impl<N: Number> Resembler<N, N, NumericError> for Relative {
+    fn resemblance(&mut self, query: &N, candidate: &N) -> Result<Resemblance, NumericError> {
+        if query == candidate {
+            finite(*query)?;
+            return Ok(Resemblance::Perfect);
+        }
+
+        let scale = finite(*query)?.abs().max(finite(*candidate)?.abs());
+        if self.tolerance == 0.0 {
+            return Ok(Resemblance::Disparity);
+        }
+        if self.tolerance.is_infinite() {
+            return Ok(distinct(1.0));
+        }
+
+        let relative = query.difference(*candidate) / scale;
+        let score = 1.0 - relative / self.tolerance;
+        Ok(distinct(score))
+    }
+}
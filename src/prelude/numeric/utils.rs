@@ -0,0 +1,82 @@
+use crate::assessor::Resemblance;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum NumericError {
+    NaN,
+    Infinite,
+    /// The value must be strictly positive, as for log-scale comparison.
+    NonPositive,
+}
+
+impl core::fmt::Display for NumericError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NumericError::NaN => write!(f, "value is NaN"),
+            NumericError::Infinite => write!(f, "value is infinite"),
+            NumericError::NonPositive => write!(f, "value must be greater than zero"),
+        }
+    }
+}
+
+impl std::error::Error for NumericError {}
+
+/// Primitive numbers the numeric resemblers accept
+pub trait Number: Copy + PartialEq + Send + Sync {
+    fn to_f64(self) -> f64;
+
+    /// Absolute difference, computed exactly for integers before converting to `f64`.
+    fn difference(self, other: Self) -> f64;
+}
+
+macro_rules! integer {
+    ($($kind:ty),*) => {
+        $(
+            impl Number for $kind {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn difference(self, other: Self) -> f64 {
+                    self.abs_diff(other) as f64
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! float {
+    ($($kind:ty),*) => {
+        $(
+            impl Number for $kind {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn difference(self, other: Self) -> f64 {
+                    (self as f64 - other as f64).abs()
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+float!(f32, f64);
+
+/// Converts a number to `f64`, rejecting NaN and infinity.
+pub fn finite<N: Number>(value: N) -> Result<f64, NumericError> {
+    let value = value.to_f64();
+    if value.is_nan() {
+        Err(NumericError::NaN)
+    } else if value.is_infinite() {
+        Err(NumericError::Infinite)
+    } else {
+        Ok(value)
+    }
+}
+
+/// Score for values known to differ, kept below a perfect match even when `f64` rounding of
+/// large integers would make them look equal.
+pub fn distinct(score: f64) -> Resemblance {
+    score.clamp(0.0, 1.0 - f64::EPSILON).into()
+}
@@ -0,0 +1,33 @@
+use {
+    core::fmt::{
+        Debug, Formatter
+    },
+    crate::{
+        person::*,
+        prelude::person::matcher::{FamilyPart, GivenPart, MiddlePart},
+    },
+};
+
+impl Debug for Person {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "name the same person")
+    }
+}
+
+impl Debug for FamilyPart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share a family name")
+    }
+}
+
+impl Debug for GivenPart<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share a given name")
+    }
+}
+
+impl Debug for MiddlePart<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share middle names")
+    }
+}
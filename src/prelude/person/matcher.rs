@@ -0,0 +1,216 @@
+use crate::{
+    assessor::{Assessor, Resembler, Resemblance, Scheme},
+    prelude::{
+        person::{
+            nicknames::{Diminutives, Nicknames},
+            utils::{initial, Name},
+        },
+        string::{Jaro, Phonetic, PhoneticMode},
+    },
+};
+
+/// Score for an initial standing for a full name with the same first letter.
+const INITIAL: f64 = 0.9;
+
+/// Score for two names the nickname table holds as equivalent.
+const NICKNAME: f64 = 0.95;
+
+/// Penalty for matching only once given and family names are exchanged.
+const REORDERED: f64 = 0.95;
+
+/// Score both the given and the family name must reach once exchanged for the exchange to count.
+const SUPPORT: f64 = 0.9;
+
+/// Jaro–Winkler similarity between two name parts, raised halfway towards the Double Metaphone
+/// score when they sound alike, so homophones gain without every shared sound passing.
+fn similarity(first: &str, second: &str) -> f64 {
+    let first = first.to_lowercase();
+    let second = second.to_lowercase();
+    if first == second {
+        return 1.0;
+    }
+
+    let spelled = Jaro::default().resemblance(&first, &second).map_or(0.0, |resemblance| resemblance.to_f64());
+    let sounded = Phonetic::new(PhoneticMode::DoubleMetaphone)
+        .resemblance(&first, &second)
+        .map_or(0.0, |resemblance| resemblance.to_f64());
+
+    spelled.max((spelled + sounded) / 2.0)
+}
+
+/// A given or middle name against another, allowing initials and nicknames.
+fn forename(first: &str, second: &str, nicknames: &dyn Nicknames) -> f64 {
+    let first = first.trim_end_matches('.');
+    let second = second.trim_end_matches('.');
+
+    if initial(first) || initial(second) {
+        let letter = |name: &str| name.chars().next().map(|c| c.to_lowercase().collect::<String>());
+        return match (letter(first), letter(second)) {
+            (Some(first_letter), Some(second_letter)) if first_letter == second_letter => {
+                if first.chars().count() == second.chars().count() { 1.0 } else { INITIAL }
+            }
+            _ => 0.0,
+        };
+    }
+    if nicknames.equivalent(first, second) {
+        return similarity(first, second).max(NICKNAME);
+    }
+
+    similarity(first, second)
+}
+
+/// Family names, with and without particles
+pub(crate) struct FamilyPart;
+
+impl Resembler<Name, Name, ()> for FamilyPart {
+    fn resemblance(&mut self, query: &Name, candidate: &Name) -> Result<Resemblance, ()> {
+        let full = similarity(&query.family, &candidate.family);
+        let core = similarity(&query.core_family(), &candidate.core_family());
+        Ok(full.max(core).clamp(0.0, 1.0).into())
+    }
+}
+
+/// Given names, through initials and nicknames
+pub(crate) struct GivenPart<'person> {
+    pub(crate) nicknames: &'person dyn Nicknames,
+}
+
+impl Resembler<Name, Name, ()> for GivenPart<'_> {
+    fn resemblance(&mut self, query: &Name, candidate: &Name) -> Result<Resemblance, ()> {
+        let score = forename(&query.given, &candidate.given, self.nicknames);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+/// Middle names paired in order, averaged over the longer list
+pub(crate) struct MiddlePart<'person> {
+    pub(crate) nicknames: &'person dyn Nicknames,
+}
+
+impl Resembler<Name, Name, ()> for MiddlePart<'_> {
+    fn resemblance(&mut self, query: &Name, candidate: &Name) -> Result<Resemblance, ()> {
+        let longest = query.middle.len().max(candidate.middle.len());
+        if longest == 0 {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let total: f64 = query.middle.iter()
+            .zip(&candidate.middle)
+            .map(|(first, second)| forename(first, second, self.nicknames))
+            .sum();
+        Ok((total / longest as f64).clamp(0.0, 1.0).into())
+    }
+}
+
+/// Personal names compared part by part: given names through initials and nicknames, family
+/// names with and without particles, in either written order
+pub struct Person {
+    nicknames: Box<dyn Nicknames>,
+    given: f64,
+    middle: f64,
+    family: f64,
+    scheme: Scheme,
+    reorder: bool,
+}
+
+impl Default for Person {
+    fn default() -> Self {
+        Self {
+            nicknames: Box::new(Diminutives::default()),
+            given: 0.35,
+            middle: 0.15,
+            family: 0.5,
+            scheme: Scheme::Additive,
+            reorder: true,
+        }
+    }
+}
+
+impl Person {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn nicknames<N: Nicknames + 'static>(mut self, nicknames: N) -> Self {
+        self.nicknames = Box::new(nicknames);
+        self
+    }
+
+    /// Weights of the given, middle and family names. A part missing from either name, such as
+    /// a middle name only one record carries, is left out rather than scored as a mismatch.
+    pub fn weights(mut self, given: f64, middle: f64, family: f64) -> Self {
+        self.given = given.max(0.0);
+        self.middle = middle.max(0.0);
+        self.family = family.max(0.0);
+        self
+    }
+
+    /// How the part scores combine, additive by default; `Scheme::Minimum` makes every part count.
+    pub fn scheme(mut self, scheme: Scheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Whether a candidate is also tried with given and family names exchanged, for names
+    /// written family first without a comma; the exchange counts only when both exchanged
+    /// names match closely.
+    pub fn reorder(mut self, reorder: bool) -> Self {
+        self.reorder = reorder;
+        self
+    }
+
+    fn compare(&self, query: &Name, candidate: &Name) -> Result<f64, ()> {
+        let mut family = FamilyPart;
+        let mut given = GivenPart { nicknames: &*self.nicknames };
+        let mut middle = MiddlePart { nicknames: &*self.nicknames };
+
+        let mut assessor = Assessor::new().scheme(self.scheme.clone()).dimension(&mut family, self.family);
+        if !query.given.is_empty() && !candidate.given.is_empty() {
+            assessor = assessor.dimension(&mut given, self.given);
+        }
+        if !query.middle.is_empty() && !candidate.middle.is_empty() {
+            assessor = assessor.dimension(&mut middle, self.middle);
+        }
+
+        Ok(assessor.resemblance(query, candidate)?.to_f64())
+    }
+
+    fn compute_resemblance(&self, query: &Name, candidate: &Name) -> Result<f64, ()> {
+        let direct = self.compare(query, candidate)?;
+        if !self.reorder || candidate.given.is_empty() {
+            return Ok(direct);
+        }
+
+        let swapped = candidate.swapped();
+        let family = FamilyPart.resemblance(query, &swapped)?.to_f64();
+        let given = GivenPart { nicknames: &*self.nicknames }.resemblance(query, &swapped)?.to_f64();
+        if family < SUPPORT || given < SUPPORT {
+            return Ok(direct);
+        }
+
+        let reordered = self.compare(query, &swapped)? * REORDERED;
+        Ok(direct.max(reordered))
+    }
+}
+
+impl Resembler<Name, Name, ()> for Person {
+    fn resemblance(&mut self, query: &Name, candidate: &Name) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate)?;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+impl Resembler<String, String, ()> for Person {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(&Name::parse(query), &Name::parse(candidate))?;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod utils;
+
+pub mod nicknames;
+pub mod matcher;
+mod format;
+
+pub use utils::*;
+pub use nicknames::*;
+pub use matcher::*;
\ No newline at end of file
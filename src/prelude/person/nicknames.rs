@@ -0,0 +1,140 @@
+use hashish::HashMap;
+
+/// Decides whether two given names are forms of the same name, such as `Bob` and `Robert`
+pub trait Nicknames: Send + Sync {
+    fn equivalent(&self, first: &str, second: &str) -> bool;
+}
+
+impl<F: Fn(&str, &str) -> bool + Send + Sync> Nicknames for F {
+    fn equivalent(&self, first: &str, second: &str) -> bool {
+        self(first, second)
+    }
+}
+
+/// Common English given names with their diminutives, one group per formal name.
+const ENGLISH: &[&[&str]] = &[
+    &["robert", "bob", "bobby", "rob", "robbie", "bert"],
+    &["william", "bill", "billy", "will", "willy", "liam"],
+    &["richard", "rick", "ricky", "dick", "rich"],
+    &["james", "jim", "jimmy", "jamie"],
+    &["john", "jack", "johnny", "jon"],
+    &["jonathan", "jon", "jonny"],
+    &["joseph", "joe", "joey"],
+    &["charles", "charlie", "chuck", "chas"],
+    &["thomas", "tom", "tommy"],
+    &["michael", "mike", "mikey", "mick"],
+    &["edward", "ed", "eddie", "ted", "ned"],
+    &["elizabeth", "liz", "lizzie", "beth", "betty", "eliza", "libby", "bess"],
+    &["margaret", "maggie", "meg", "peggy", "marge", "margie", "greta"],
+    &["katherine", "catherine", "kate", "kathy", "katie", "kat", "cathy"],
+    &["christopher", "chris", "kit"],
+    &["christine", "chris", "chrissy", "tina"],
+    &["daniel", "dan", "danny"],
+    &["david", "dave", "davey"],
+    &["anthony", "tony"],
+    &["andrew", "andy", "drew"],
+    &["alexander", "alex", "al", "sandy", "xander"],
+    &["alexandra", "alex", "sandra", "sasha"],
+    &["albert", "al", "bert"],
+    &["alfred", "alf", "alfie", "fred"],
+    &["frederick", "fred", "freddie"],
+    &["benjamin", "ben", "benny"],
+    &["samuel", "sam", "sammy"],
+    &["samantha", "sam"],
+    &["matthew", "matt"],
+    &["nicholas", "nick", "nicky"],
+    &["patrick", "pat", "paddy"],
+    &["patricia", "pat", "patty", "trish", "tricia"],
+    &["steven", "stephen", "steve"],
+    &["timothy", "tim", "timmy"],
+    &["kenneth", "ken", "kenny"],
+    &["lawrence", "larry"],
+    &["ronald", "ron", "ronnie"],
+    &["donald", "don", "donnie"],
+    &["gerald", "gerry", "jerry"],
+    &["peter", "pete"],
+    &["jennifer", "jen", "jenny"],
+    &["jessica", "jess", "jessie"],
+    &["susan", "sue", "susie", "suzy"],
+    &["deborah", "debbie", "deb"],
+    &["rebecca", "becky", "becca"],
+    &["victoria", "vicky", "tori"],
+    &["barbara", "barb", "barbie"],
+    &["dorothy", "dot", "dottie"],
+    &["frances", "fran", "frannie"],
+    &["francis", "frank"],
+    &["henry", "hank", "harry"],
+    &["harold", "hal", "harry"],
+    &["theodore", "ted", "teddy", "theo"],
+    &["eugene", "gene"],
+    &["leonard", "len", "lenny", "leo"],
+    &["raymond", "ray"],
+    &["walter", "walt", "wally"],
+    &["zachary", "zach", "zack"],
+    &["abigail", "abby"],
+    &["pamela", "pam"],
+    &["cynthia", "cindy"],
+    &["judith", "judy"],
+    &["sarah", "sally", "sadie"],
+    &["mary", "molly", "polly", "mae"],
+    &["ann", "anne", "anna", "annie", "nancy"],
+    &["helen", "nell", "nellie"],
+    &["louis", "lou"],
+    &["vincent", "vince", "vinny"],
+    &["douglas", "doug"],
+    &["gregory", "greg"],
+    &["jeffrey", "jeff"],
+    &["joshua", "josh"],
+    &["nathaniel", "nathan", "nate", "nat"],
+    &["phillip", "philip", "phil"],
+    &["russell", "russ"],
+    &["stanley", "stan"],
+    &["terence", "terry"],
+];
+
+/// Nickname table of name groups, where any two names sharing a group are equivalent; a name
+/// may sit in several groups, as `Al` does for both `Albert` and `Alexander`.
+///
+/// `Diminutives::default()` holds common English names; `Diminutives::new()` starts empty.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diminutives {
+    groups: HashMap<String, Vec<usize>>,
+    count: usize,
+}
+
+impl Default for Diminutives {
+    fn default() -> Self {
+        let mut diminutives = Self::new();
+        for group in ENGLISH {
+            diminutives.insert(group);
+        }
+        diminutives
+    }
+}
+
+impl Diminutives {
+    pub fn new() -> Self {
+        Self { groups: HashMap::new(), count: 0 }
+    }
+
+    pub fn group(mut self, names: &[&str]) -> Self {
+        self.insert(names);
+        self
+    }
+
+    pub fn insert(&mut self, names: &[&str]) {
+        for name in names {
+            self.groups.entry(name.to_lowercase()).or_default().push(self.count);
+        }
+        self.count += 1;
+    }
+}
+
+impl Nicknames for Diminutives {
+    fn equivalent(&self, first: &str, second: &str) -> bool {
+        match (self.groups.get(&first.to_lowercase()), self.groups.get(&second.to_lowercase())) {
+            (Some(first), Some(second)) => first.iter().any(|group| second.contains(group)),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,119 @@
+/// Lowercase words joined to the family name that follows them, as in `van der Berg`.
+pub const PARTICLES: [&str; 27] = [
+    "van", "von", "der", "den", "de", "del", "della", "dela", "di", "da", "das", "do", "dos", "du",
+    "des", "la", "le", "lo", "ter", "ten", "op", "zu", "bin", "ibn", "bint", "al", "el",
+];
+
+/// Hyphenated particles written onto the family name, as in `al-Rashid`.
+const PREFIXES: [&str; 4] = ["al-", "el-", "ad-", "as-"];
+
+const TITLES: [&str; 12] = ["mr", "mrs", "ms", "miss", "mx", "dr", "prof", "sir", "dame", "madam", "rev", "hon"];
+
+const SUFFIXES: [&str; 9] = ["jr", "sr", "ii", "iii", "iv", "phd", "md", "esq", "dds"];
+
+/// Whether a name part is an initial, such as `J` or `J.`.
+pub fn initial(part: &str) -> bool {
+    part.trim_end_matches('.').chars().count() == 1
+}
+
+/// Whether a word is a name particle, ignoring case.
+pub fn particle(word: &str) -> bool {
+    let lowered = word.to_lowercase();
+    PARTICLES.contains(&lowered.as_str())
+}
+
+fn honorific(word: &str, list: &[&str]) -> bool {
+    let lowered = word.trim_end_matches('.').to_lowercase();
+    list.contains(&lowered.as_str())
+}
+
+/// Words of a name, with titles and suffixes dropped and run-together initials such as `J.R.` split.
+fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for word in text.split_whitespace() {
+        let word = word.trim_matches(',');
+        let pieces: Vec<&str> = word.split('.').filter(|piece| !piece.is_empty()).collect();
+        if pieces.len() > 1 && pieces.iter().all(|piece| piece.chars().count() == 1) {
+            words.extend(pieces.into_iter().map(str::to_string));
+        } else if !word.is_empty() && !honorific(word, &TITLES) && !honorific(word, &SUFFIXES) {
+            words.push(word.trim_end_matches('.').to_string());
+        }
+    }
+    words
+}
+
+/// A personal name split into its given, middle and family parts
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Name {
+    pub given: String,
+    pub middle: Vec<String>,
+    pub family: String,
+}
+
+impl Name {
+    pub fn new(given: &str, middle: &[&str], family: &str) -> Self {
+        Self {
+            given: given.to_string(),
+            middle: middle.iter().map(|part| part.to_string()).collect(),
+            family: family.to_string(),
+        }
+    }
+
+    /// Parses `Given Middle Family` or `Family, Given Middle`, dropping titles and suffixes.
+    ///
+    /// Particles before the last word belong to the family name, so `Ludwig van Beethoven`
+    /// has `van Beethoven` as its family; a single word is taken as the family name. A comma
+    /// followed only by titles or suffixes, as in `John Smith, Jr.`, does not reverse the order.
+    pub fn parse(text: &str) -> Self {
+        if let Some((family, given)) = text.split_once(',') {
+            let mut given = words(given).into_iter().peekable();
+            if given.peek().is_none() {
+                return Self::parse(family);
+            }
+
+            let family = words(family);
+            if !family.is_empty() {
+                return Self {
+                    given: given.next().unwrap_or_default(),
+                    middle: given.collect(),
+                    family: family.join(" "),
+                };
+            }
+        }
+
+        let mut words = words(text);
+        let Some(last) = words.len().checked_sub(1) else {
+            return Self::default();
+        };
+
+        let mut start = last;
+        while start > 1 && particle(&words[start - 1]) {
+            start -= 1;
+        }
+
+        let family = words.split_off(start).join(" ");
+        let mut rest = words.into_iter();
+        Self { given: rest.next().unwrap_or_default(), middle: rest.collect(), family }
+    }
+
+    /// The family name without particles or hyphenated prefixes, lowercased: `van der Berg`
+    /// and `al-Rashid` give `berg` and `rashid`.
+    pub fn core_family(&self) -> String {
+        self.family
+            .split_whitespace()
+            .filter(|word| !particle(word))
+            .map(|word| {
+                let lowered = word.to_lowercase();
+                PREFIXES.iter()
+                    .find_map(|prefix| lowered.strip_prefix(prefix))
+                    .map_or(lowered.clone(), str::to_string)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The same name with given and family parts exchanged, for names written family first.
+    pub fn swapped(&self) -> Self {
+        Self { given: self.family.clone(), middle: self.middle.clone(), family: self.given.clone() }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    assessor::{Resembler, Resemblance},
+    prelude::sequence::utils::{alignment, assignment, monge_elkan},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Pairing {
+    /// Order-sensitive Needleman–Wunsch alignment, each skipped element costing `gap`.
+    Alignment { gap: f64 },
+    /// Order-insensitive optimal one-to-one assignment.
+    Assignment,
+    /// Each query element takes its best candidate element, averaged.
+    MongeElkan,
+}
+
+impl Default for Pairing {
+    fn default() -> Self {
+        Pairing::Alignment { gap: 0.0 }
+    }
+}
+
+/// Lists compared element by element through an inner resembler
+pub struct Elements<'elements, Item, Error> {
+    pub resembler: &'elements mut dyn Resembler<Item, Item, Error>,
+    pub pairing: Pairing,
+}
+
+impl<'elements, Item, Error> Elements<'elements, Item, Error> {
+    pub fn new<R: Resembler<Item, Item, Error> + 'elements>(resembler: &'elements mut R, pairing: Pairing) -> Self {
+        Self { resembler, pairing }
+    }
+
+    fn matrix(&mut self, query: &[Item], candidate: &[Item]) -> Result<Vec<Vec<f64>>, Error> {
+        let mut matrix = Vec::with_capacity(query.len());
+        for query_item in query {
+            let mut row = Vec::with_capacity(candidate.len());
+            for candidate_item in candidate {
+                let score = self.resembler.resemblance(query_item, candidate_item)?.to_f64();
+                row.push(if score.is_finite() { score } else { 0.0 });
+            }
+            matrix.push(row);
+        }
+        Ok(matrix)
+    }
+
+    pub fn compute_resemblance(&mut self, query: &[Item], candidate: &[Item]) -> Result<f64, Error> {
+        if query.is_empty() && candidate.is_empty() {
+            return Ok(1.0);
+        }
+        if query.is_empty() || candidate.is_empty() {
+            return Ok(0.0);
+        }
+
+        let matrix = self.matrix(query, candidate)?;
+        let longest = query.len().max(candidate.len()) as f64;
+
+        let score = match self.pairing {
+            Pairing::Alignment { gap } => alignment(&matrix, candidate.len(), gap) / longest,
+            Pairing::Assignment => assignment(&matrix, candidate.len()).0 / longest,
+            Pairing::MongeElkan => monge_elkan(&matrix),
+        };
+
+        Ok(score)
+    }
+}
+
+impl<'elements, Item, Error> Resembler<[Item], [Item], Error> for Elements<'elements, Item, Error> {
+    fn resemblance(&mut self, query: &[Item], candidate: &[Item]) -> Result<Resemblance, Error> {
+        let score = self.compute_resemblance(query, candidate)?;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+impl<'elements, Item, Error> Resembler<Vec<Item>, Vec<Item>, Error> for Elements<'elements, Item, Error> {
+    fn resemblance(&mut self, query: &Vec<Item>, candidate: &Vec<Item>) -> Result<Resemblance, Error> {
+        let score = self.compute_resemblance(query, candidate)?;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
@@ -0,0 +1,18 @@
+use {
+    core::fmt::{
+        Debug, Formatter
+    },
+    crate::{
+        sequence::*,
+    },
+};
+
+impl<Item, Error> Debug for Elements<'_, Item, Error> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.pairing {
+            Pairing::Alignment { .. } => write!(f, "have matching elements in the same order"),
+            Pairing::Assignment => write!(f, "have matching elements"),
+            Pairing::MongeElkan => write!(f, "have elements resembling each other"),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod utils;
+
+pub mod elements;
+mod format;
+
+pub use utils::*;
+pub use elements::*;
@@ -0,0 +1,122 @@
+/// Best order-preserving alignment of rows to columns (Needleman–Wunsch), each pair
+/// earning its similarity and each skipped element costing `gap`.
+pub fn alignment(matrix: &[Vec<f64>], columns: usize, gap: f64) -> f64 {
+    let rows = matrix.len();
+    let mut table = vec![vec![0.0; columns + 1]; rows + 1];
+
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = -(i as f64) * gap;
+    }
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = -(j as f64) * gap;
+    }
+
+    for i in 1..=rows {
+        for j in 1..=columns {
+            table[i][j] = (table[i - 1][j - 1] + matrix[i - 1][j - 1])
+                .max(table[i - 1][j] - gap)
+                .max(table[i][j - 1] - gap);
+        }
+    }
+
+    table[rows][columns]
+}
+
+/// Maximum-weight one-to-one assignment of rows to columns (Hungarian algorithm),
+/// returning the total similarity and the column chosen for each row; non-finite
+/// similarities count as zero.
+pub fn assignment(matrix: &[Vec<f64>], columns: usize) -> (f64, Vec<Option<usize>>) {
+    let rows = matrix.len();
+    if rows == 0 || columns == 0 {
+        return (0.0, vec![None; rows]);
+    }
+
+    let transposed = rows > columns;
+    let (n, m) = if transposed { (columns, rows) } else { (rows, columns) };
+    let similarity = |row: usize, column: usize| {
+        let value = matrix[row][column];
+        if value.is_finite() { value } else { 0.0 }
+    };
+    let cost = |i: usize, j: usize| 1.0 - if transposed { similarity(j, i) } else { similarity(i, j) };
+
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; m + 1];
+    let mut owner = vec![0usize; m + 1];
+    let mut way = vec![0usize; m + 1];
+
+    for i in 1..=n {
+        owner[0] = i;
+        let mut column = 0;
+        let mut minimum = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+
+        loop {
+            used[column] = true;
+            let row = owner[column];
+            let mut delta = f64::INFINITY;
+            let mut next = 0;
+
+            for j in 1..=m {
+                if !used[j] {
+                    let reduced = cost(row - 1, j - 1) - u[row] - v[j];
+                    if reduced < minimum[j] {
+                        minimum[j] = reduced;
+                        way[j] = column;
+                    }
+                    if minimum[j] < delta {
+                        delta = minimum[j];
+                        next = j;
+                    }
+                }
+            }
+
+            for j in 0..=m {
+                if used[j] {
+                    u[owner[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    minimum[j] -= delta;
+                }
+            }
+
+            column = next;
+            if owner[column] == 0 {
+                break;
+            }
+        }
+
+        loop {
+            let previous = way[column];
+            owner[column] = owner[previous];
+            column = previous;
+            if column == 0 {
+                break;
+            }
+        }
+    }
+
+    let mut chosen = vec![None; rows];
+    let mut total = 0.0;
+    for (j, &assigned) in owner.iter().enumerate().skip(1) {
+        if assigned != 0 {
+            let (row, column) = if transposed { (j - 1, assigned - 1) } else { (assigned - 1, j - 1) };
+            chosen[row] = Some(column);
+            total += similarity(row, column);
+        }
+    }
+
+    (total, chosen)
+}
+
+/// Mean over rows of each row's best similarity (Monge–Elkan).
+pub fn monge_elkan(matrix: &[Vec<f64>]) -> f64 {
+    if matrix.is_empty() {
+        return 0.0;
+    }
+
+    let total: f64 = matrix.iter()
+        .map(|row| row.iter().copied().fold(0.0, f64::max))
+        .sum();
+
+    total / matrix.len() as f64
+}
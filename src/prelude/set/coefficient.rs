@@ -0,0 +1,172 @@
+use {
+    core::hash::Hash,
+    crate::{
+        assessor::{Resembler, Resemblance},
+        prelude::set::utils::{counts, tally},
+    },
+};
+
+macro_rules! collection {
+    ($resembler:ty) => {
+        impl<C, T> Resembler<C, C, ()> for $resembler
+        where
+            C: ?Sized,
+            for<'c> &'c C: IntoIterator<Item = &'c T>,
+            T: Hash + Eq,
+        {
+            fn resemblance(&mut self, query: &C, candidate: &C) -> Result<Resemblance, ()> {
+                let score = self.compute_resemblance(query, candidate);
+                Ok(score.clamp(0.0, 1.0).into())
+            }
+        }
+    };
+}
+
+/// Jaccard index, intersection over union
+#[derive(Default, PartialEq)]
+pub struct Jaccard {
+    multiset: bool,
+}
+
+impl Jaccard {
+    pub fn new(multiset: bool) -> Self {
+        Self { multiset }
+    }
+
+    pub fn compute_resemblance<I: IntoIterator>(&self, query: I, candidate: I) -> f64
+    where
+        I::Item: Hash + Eq,
+    {
+        let (intersection, query_size, candidate_size) = tally(&counts(query, self.multiset), &counts(candidate, self.multiset));
+        let union = query_size + candidate_size - intersection;
+        if union == 0 { 1.0 } else { intersection as f64 / union as f64 }
+    }
+}
+
+collection!(Jaccard);
+
+/// Sørensen–Dice coefficient, twice the intersection over the summed sizes
+#[derive(Default, PartialEq)]
+pub struct Dice {
+    multiset: bool,
+}
+
+impl Dice {
+    pub fn new(multiset: bool) -> Self {
+        Self { multiset }
+    }
+
+    pub fn compute_resemblance<I: IntoIterator>(&self, query: I, candidate: I) -> f64
+    where
+        I::Item: Hash + Eq,
+    {
+        let (intersection, query_size, candidate_size) = tally(&counts(query, self.multiset), &counts(candidate, self.multiset));
+        let total = query_size + candidate_size;
+        if total == 0 { 1.0 } else { 2.0 * intersection as f64 / total as f64 }
+    }
+}
+
+collection!(Dice);
+
+/// Tversky index, weighing what only the query has by `alpha` and what only the candidate has by `beta`.
+///
+/// `alpha = 1, beta = 0` scores how much of the query the candidate contains.
+#[derive(PartialEq)]
+pub struct Tversky {
+    alpha: f64,
+    beta: f64,
+    multiset: bool,
+}
+
+impl Default for Tversky {
+    fn default() -> Self {
+        Self { alpha: 0.5, beta: 0.5, multiset: false }
+    }
+}
+
+impl Tversky {
+    pub fn new(alpha: f64, beta: f64, multiset: bool) -> Self {
+        Self { alpha: alpha.abs(), beta: beta.abs(), multiset }
+    }
+
+    pub fn compute_resemblance<I: IntoIterator>(&self, query: I, candidate: I) -> f64
+    where
+        I::Item: Hash + Eq,
+    {
+        let (intersection, query_size, candidate_size) = tally(&counts(query, self.multiset), &counts(candidate, self.multiset));
+        let denominator = intersection as f64
+            + self.alpha * (query_size - intersection) as f64
+            + self.beta * (candidate_size - intersection) as f64;
+
+        if query_size == 0 && candidate_size == 0 {
+            1.0
+        } else if denominator == 0.0 {
+            0.0
+        } else {
+            intersection as f64 / denominator
+        }
+    }
+}
+
+collection!(Tversky);
+
+/// Overlap coefficient (Szymkiewicz–Simpson), intersection over the smaller size
+#[derive(Default, PartialEq)]
+pub struct Simpson {
+    multiset: bool,
+}
+
+impl Simpson {
+    pub fn new(multiset: bool) -> Self {
+        Self { multiset }
+    }
+
+    pub fn compute_resemblance<I: IntoIterator>(&self, query: I, candidate: I) -> f64
+    where
+        I::Item: Hash + Eq,
+    {
+        let (intersection, query_size, candidate_size) = tally(&counts(query, self.multiset), &counts(candidate, self.multiset));
+        match query_size.min(candidate_size) {
+            0 if query_size == candidate_size => 1.0,
+            0 => 0.0,
+            smaller => intersection as f64 / smaller as f64,
+        }
+    }
+}
+
+collection!(Simpson);
+
+/// Cosine similarity over occurrence counts (the Ochiai coefficient for sets)
+#[derive(Default, PartialEq)]
+pub struct Ochiai {
+    multiset: bool,
+}
+
+impl Ochiai {
+    pub fn new(multiset: bool) -> Self {
+        Self { multiset }
+    }
+
+    pub fn compute_resemblance<I: IntoIterator>(&self, query: I, candidate: I) -> f64
+    where
+        I::Item: Hash + Eq,
+    {
+        let query_counts = counts(query, self.multiset);
+        let candidate_counts = counts(candidate, self.multiset);
+
+        if query_counts.is_empty() || candidate_counts.is_empty() {
+            return if query_counts.is_empty() && candidate_counts.is_empty() { 1.0 } else { 0.0 };
+        }
+
+        let dot_product = query_counts.iter()
+            .filter_map(|(item, count)| candidate_counts.get(item).map(|other| (*count * *other) as f64))
+            .sum::<f64>();
+
+        let query_norm = query_counts.values().map(|c| (*c as f64).powi(2)).sum::<f64>().sqrt();
+        let candidate_norm = candidate_counts.values().map(|c| (*c as f64).powi(2)).sum::<f64>().sqrt();
+
+        dot_product / (query_norm * candidate_norm)
+    }
+}
+
+collection!(Ochiai);
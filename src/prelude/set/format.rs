@@ -0,0 +1,38 @@
+use {
+    core::fmt::{
+        Debug, Formatter
+    },
+    crate::{
+        set::*,
+    },
+};
+
+impl Debug for Jaccard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share most of their items")
+    }
+}
+
+impl Debug for Dice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share many of their items")
+    }
+}
+
+impl Debug for Tversky {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "contain each other's items")
+    }
+}
+
+impl Debug for Simpson {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "overlap in their items")
+    }
+}
+
+impl Debug for Ochiai {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "have similar item counts")
+    }
+}
@@ -0,0 +1,7 @@
+pub mod utils;
+
+pub mod coefficient;
+mod format;
+
+pub use utils::*;
+pub use coefficient::*;
@@ -0,0 +1,29 @@
+use {
+    core::hash::Hash,
+    hashish::HashMap,
+};
+
+/// Occurrences of each distinct item, capped at one per item unless `multiset` is set.
+pub fn counts<I>(items: I, multiset: bool) -> HashMap<I::Item, usize>
+where
+    I: IntoIterator,
+    I::Item: Hash + Eq,
+{
+    let mut counts = HashMap::new();
+    for item in items {
+        let count = counts.entry(item).or_insert(0);
+        if multiset || *count == 0 {
+            *count += 1;
+        }
+    }
+    counts
+}
+
+/// Sizes of the intersection and of each side, by count.
+pub fn tally<K: Hash + Eq>(query: &HashMap<K, usize>, candidate: &HashMap<K, usize>) -> (usize, usize, usize) {
+    let intersection = query.iter()
+        .filter_map(|(item, count)| candidate.get(item).map(|other| (*count).min(*other)))
+        .sum();
+
+    (intersection, query.values().sum(), candidate.values().sum())
+}
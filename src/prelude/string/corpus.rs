@@ -0,0 +1,251 @@
+//! Corpus statistics fitted once over a candidate set.
+//!
+//! A [`Corpus`] encodes to a small binary file: a 48-byte header, the 8-byte
+//! fingerprint of its [`Words`] settings, one 12-byte record per term (text span
+//! as a `u32` pair, document frequency `u32`) in ascending byte order, then the
+//! UTF-8 blob the spans point into. Integers are little-endian and the header
+//! and checksum are shared with the index format.
+//!
+//! ```text
+//! 0       8         12     16     20          24       32      36     40         48      56
+//! | magic | version | kind | size | documents | length | terms | blob | checksum | words |
+//! ```
+//!
+//! Tokenizers and stemmers cannot be stored, so a word corpus must be loaded with
+//! [`Corpus::decode_with`] or [`Corpus::load_with`] and the same settings it was fitted
+//! with; the fingerprint rejects any others.
+
+use {
+    hashish::HashMap,
+    std::{collections::BTreeMap, path::Path},
+    crate::{
+        index::storage::{narrow, read_header, read_u32, read_u64, seal, verify, write_file, IndexError, HEADER},
+        prelude::string::{Cosine, Words},
+    },
+};
+
+pub const CORPUS_MAGIC: [u8; 8] = *b"MATCHIDF";
+pub const CORPUS_VERSION: u32 = 2;
+
+const FINGERPRINT: usize = 8;
+const TERM: usize = 12;
+
+/// What a corpus counts as a term
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Terms {
+    /// Words as split by the corpus' [`Words`] settings.
+    Words,
+    /// Character n-grams of the given size, as extracted by [`Cosine`].
+    Ngrams(usize),
+}
+
+/// Document frequencies of every term in a fitted candidate set
+#[derive(PartialEq)]
+pub struct Corpus {
+    terms: Terms,
+    words: Words,
+    documents: usize,
+    length: usize,
+    frequencies: HashMap<String, usize>,
+}
+
+impl Corpus {
+    pub fn new(terms: Terms) -> Self {
+        Self {
+            terms,
+            words: Words::default(),
+            documents: 0,
+            length: 0,
+            frequencies: HashMap::new(),
+        }
+    }
+
+    /// Tokenizer settings used when terms are [`Terms::Words`].
+    pub fn words(mut self, words: Words) -> Self {
+        self.words = words;
+        self
+    }
+
+    pub fn fit<I, S>(mut self, documents: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for document in documents {
+            self.insert(document.as_ref());
+        }
+        self
+    }
+
+    pub fn insert(&mut self, document: &str) {
+        let counts = self.counts(document);
+        self.length += counts.values().sum::<usize>();
+        for term in counts.into_keys() {
+            *self.frequencies.entry(term).or_insert(0) += 1;
+        }
+        self.documents += 1;
+    }
+
+    pub fn get_terms(&self) -> Terms {
+        self.terms
+    }
+
+    pub fn get_documents(&self) -> usize {
+        self.documents
+    }
+
+    pub fn get_frequency(&self, term: &str) -> usize {
+        self.frequencies.get(term).copied().unwrap_or(0)
+    }
+
+    /// Mean number of terms per fitted document.
+    pub fn average_length(&self) -> f64 {
+        if self.documents == 0 { 0.0 } else { self.length as f64 / self.documents as f64 }
+    }
+
+    /// How often each term occurs in `text`.
+    pub fn counts(&self, text: &str) -> HashMap<String, usize> {
+        match self.terms {
+            Terms::Words => {
+                let mut counts = HashMap::new();
+                for word in self.words.extract_words(text) {
+                    *counts.entry(word).or_insert(0) += 1;
+                }
+                counts
+            }
+            Terms::Ngrams(size) => Cosine::new(size).extract_ngrams(text),
+        }
+    }
+
+    /// Smoothed inverse document frequency; terms never seen while fitting weigh the most.
+    pub fn idf(&self, term: &str) -> f64 {
+        ((self.documents as f64 + 1.0) / (self.get_frequency(term) as f64 + 1.0)).ln() + 1.0
+    }
+
+    /// Unit-length `log(tf + 1) * idf` vector of `text`, sorted by term.
+    pub fn transform(&self, text: &str) -> Vec<(String, f64)> {
+        let mut weights: Vec<(String, f64)> = self.counts(text).into_iter()
+            .map(|(term, count)| {
+                let weight = (count as f64 + 1.0).ln() * self.idf(&term);
+                (term, weight)
+            })
+            .collect();
+        weights.sort_by(|left, right| left.0.cmp(&right.0));
+
+        let norm = weights.iter().map(|(_, weight)| weight * weight).sum::<f64>().sqrt();
+        if norm > 0.0 {
+            for (_, weight) in &mut weights {
+                *weight /= norm;
+            }
+        }
+
+        weights
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, IndexError> {
+        let sorted: BTreeMap<&str, usize> = self.frequencies.iter()
+            .map(|(term, frequency)| (term.as_str(), *frequency))
+            .collect();
+
+        let mut blob = String::new();
+        let mut term_section = Vec::with_capacity(sorted.len() * TERM);
+        for (term, frequency) in &sorted {
+            let offset = narrow(blob.len(), "string bytes")?;
+            blob.push_str(term);
+            for value in [offset, narrow(term.len(), "string bytes")?, narrow(*frequency, "documents")?] {
+                term_section.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        let (kind, size) = match self.terms {
+            Terms::Words => (0u32, 0),
+            Terms::Ngrams(size) => (1u32, size),
+        };
+
+        let mut bytes = Vec::with_capacity(HEADER + FINGERPRINT + term_section.len() + blob.len());
+        bytes.extend_from_slice(&CORPUS_MAGIC);
+        bytes.extend_from_slice(&CORPUS_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&kind.to_le_bytes());
+        bytes.extend_from_slice(&narrow(size, "gram size")?.to_le_bytes());
+        bytes.extend_from_slice(&narrow(self.documents, "documents")?.to_le_bytes());
+        bytes.extend_from_slice(&(self.length as u64).to_le_bytes());
+        bytes.extend_from_slice(&narrow(sorted.len(), "terms")?.to_le_bytes());
+        bytes.extend_from_slice(&narrow(blob.len(), "string bytes")?.to_le_bytes());
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend_from_slice(&self.words.fingerprint().to_le_bytes());
+        bytes.extend_from_slice(&term_section);
+        bytes.extend_from_slice(blob.as_bytes());
+
+        seal(&mut bytes);
+        Ok(bytes)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), IndexError> {
+        write_file(path, &self.encode()?)
+    }
+
+    /// Decodes a corpus fitted with the default [`Words`] settings.
+    pub fn decode(bytes: &[u8]) -> Result<Self, IndexError> {
+        Self::decode_with(bytes, Words::default())
+    }
+
+    /// Decodes a corpus, failing with [`IndexError::Settings`] if it counts words and was
+    /// fitted with other settings than `words`.
+    pub fn decode_with(bytes: &[u8], words: Words) -> Result<Self, IndexError> {
+        read_header(bytes, CORPUS_MAGIC, CORPUS_VERSION)?;
+
+        let count = read_u32(bytes, 32) as usize;
+        let blob_length = read_u32(bytes, 36) as usize;
+        let records = HEADER + FINGERPRINT + count.saturating_mul(TERM);
+        let expected = records.saturating_add(blob_length);
+        if bytes.len() != expected {
+            return Err(IndexError::Length { found: bytes.len(), expected });
+        }
+        verify(bytes)?;
+
+        let terms = match (read_u32(bytes, 12), read_u32(bytes, 16) as usize) {
+            (0, _) => Terms::Words,
+            (1, size) if size > 0 => Terms::Ngrams(size),
+            _ => return Err(IndexError::Corrupt("unknown term kind")),
+        };
+
+        let found = read_u64(bytes, HEADER);
+        let expected = words.fingerprint();
+        if terms == Terms::Words && found != expected {
+            return Err(IndexError::Settings { found, expected });
+        }
+
+        let blob = core::str::from_utf8(&bytes[records..])
+            .map_err(|_| IndexError::Corrupt("string blob is not valid UTF-8"))?;
+
+        let mut corpus = Corpus::new(terms).words(words);
+        corpus.documents = read_u32(bytes, 20) as usize;
+        corpus.length = read_u64(bytes, 24) as usize;
+
+        for term in 0..count {
+            let at = HEADER + FINGERPRINT + term * TERM;
+            let offset = read_u32(bytes, at) as usize;
+            let length = read_u32(bytes, at + 4) as usize;
+            let frequency = read_u32(bytes, at + 8) as usize;
+
+            let text = offset.checked_add(length)
+                .and_then(|end| blob.get(offset..end))
+                .ok_or(IndexError::Corrupt("term text out of bounds"))?;
+            if frequency > corpus.documents {
+                return Err(IndexError::Corrupt("term frequency exceeds document count"));
+            }
+            corpus.frequencies.insert(text.to_string(), frequency);
+        }
+
+        Ok(corpus)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, IndexError> {
+        Self::load_with(path, Words::default())
+    }
+
+    pub fn load_with<P: AsRef<Path>>(path: P, words: Words) -> Result<Self, IndexError> {
+        let bytes = std::fs::read(path)?;
+        Self::decode_with(&bytes, words)
+    }
+}
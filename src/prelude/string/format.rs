@@ -72,3 +72,75 @@ impl Debug for Words {
         write!(f, "share common words")
     }
 }
+
+impl Debug for MongeElkan<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share similar words")
+    }
+}
+
+impl Debug for SoftTfidf<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share similar distinctive words")
+    }
+}
+
+impl Debug for Alphanumeric<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "match in words and numbers")
+    }
+}
+
+impl Debug for TfIdf<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share distinctive terms")
+    }
+}
+
+impl Debug for Bm25<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "are relevant to each other")
+    }
+}
+
+impl Debug for Local {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "contain a close match")
+    }
+}
+
+impl Debug for Subsequence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share a long subsequence")
+    }
+}
+
+impl Debug for Substring {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share a long substring")
+    }
+}
+
+impl Debug for Global {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "align end to end")
+    }
+}
+
+impl Debug for Hamming {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "differ in few positions")
+    }
+}
+
+impl Debug for Ratcliff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "share matching blocks")
+    }
+}
+
+impl Debug for Path<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "point to the same file")
+    }
+}
@@ -4,24 +4,45 @@ use {
 
     crate::{
         assessor::{Resembler, Resemblance},
+        prelude::string::utils::{same_letter, StringError},
     }
 };
 
-/// Jaro-Winkler similarity
+/// Jaro similarity, with the Winkler prefix boost unless built as plain Jaro
 #[derive(PartialEq)]
 pub struct Jaro {
     prefix_weight: f64,
+    max_prefix: usize,
+    threshold: f64,
+    winkler: bool,
 }
 
 impl Default for Jaro {
     fn default() -> Self {
-        Self { prefix_weight: 0.1 }
+        Self { prefix_weight: 0.1, max_prefix: 4, threshold: 0.7, winkler: true }
     }
 }
 
 impl Jaro {
     pub fn new(prefix_weight: f64) -> Self {
-        Self { prefix_weight }
+        Self { prefix_weight, ..Self::default() }
+    }
+
+    /// Plain Jaro similarity, without the prefix boost.
+    pub fn plain() -> Self {
+        Self { winkler: false, ..Self::default() }
+    }
+
+    /// Longest common prefix the boost counts, 4 in Winkler's definition.
+    pub fn max_prefix(mut self, max_prefix: usize) -> Self {
+        self.max_prefix = max_prefix;
+        self
+    }
+
+    /// Jaro score the pair must exceed before the prefix boost applies, 0.7 in Winkler's paper.
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
     }
 
     fn compute_jaro(&self, str1: &str, str2: &str) -> f64 {
@@ -31,7 +52,7 @@ impl Jaro {
         if len1 == 0 && len2 == 0 { return 1.0; }
         if len1 == 0 || len2 == 0 { return 0.0; }
 
-        let match_range = max(len1, len2) / 2 - 1;
+        let match_range = (max(len1, len2) / 2).saturating_sub(1);
         let chars1: Vec<char> = str1.chars().collect();
         let chars2: Vec<char> = str2.chars().collect();
         let mut matches1 = vec![false; len1];
@@ -39,7 +60,7 @@ impl Jaro {
         let mut match_count = 0;
 
         for i in 0..len1 {
-            let start = i.saturating_sub(match_range).max(0);
+            let start = i.saturating_sub(match_range);
             let end = min(i + match_range + 1, len2);
 
             for j in start..end {
@@ -70,16 +91,19 @@ impl Jaro {
     }
 
     fn common_prefix_len(&self, str1: &str, str2: &str) -> usize {
-        let max_prefix = 4;
-        let chars1: Vec<char> = str1.chars().collect();
-        let chars2: Vec<char> = str2.chars().collect();
-        let min_len = min(chars1.len(), chars2.len()).min(max_prefix);
-
-        (0..min_len).take_while(|&i| chars1[i] == chars2[i]).count()
+        str1.chars()
+            .zip(str2.chars())
+            .take(self.max_prefix)
+            .take_while(|(first, second)| first == second)
+            .count()
     }
 
     fn compute_resemblance(&self, query: &str, candidate: &str) -> f64 {
         let jaro_score = self.compute_jaro(query, candidate);
+        if !self.winkler || jaro_score <= self.threshold {
+            return jaro_score;
+        }
+
         let prefix_len = self.common_prefix_len(query, candidate);
         jaro_score + prefix_len as f64 * self.prefix_weight * (1.0 - jaro_score)
     }
@@ -104,6 +128,56 @@ impl Resembler<String, String, ()> for Jaro {
     }
 }
 
+/// Hamming similarity for equal-length codes, the share of positions that agree
+#[derive(Default, PartialEq)]
+pub struct Hamming {
+    ignore_case: bool,
+}
+
+impl Hamming {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Number of positions at which the two strings differ.
+    pub fn distance(&self, query: &str, candidate: &str) -> Result<usize, StringError> {
+        let query_len = query.chars().count();
+        let candidate_len = candidate.chars().count();
+        if query_len != candidate_len {
+            return Err(StringError::Length { query: query_len, candidate: candidate_len });
+        }
+
+        let distance = query.chars()
+            .zip(candidate.chars())
+            .filter(|&(first, second)| if self.ignore_case { !same_letter(first, second) } else { first != second })
+            .count();
+
+        Ok(distance)
+    }
+}
+
+impl Resembler<String, String, StringError> for Hamming {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, StringError> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let distance = self.distance(query, candidate)?;
+        let length = query.chars().count();
+        if length == 0 {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = 1.0 - distance as f64 / length as f64;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
 /// Cosine similarity using n-grams
 #[derive(PartialEq)]
 pub struct Cosine {
@@ -121,14 +195,14 @@ impl Cosine {
         Self { ngram_size: ngram_size.max(1) }
     }
 
-    fn extract_ngrams(&self, text: &str) -> HashMap<String, usize> {
+    pub(crate) fn extract_ngrams(&self, text: &str) -> HashMap<String, usize> {
         let mut ngrams = HashMap::new();
-        if text.len() < self.ngram_size {
+        let chars: Vec<char> = text.chars().collect();
+        if chars.len() < self.ngram_size {
             if !text.is_empty() { ngrams.insert(text.to_string(), 1); }
             return ngrams;
         }
 
-        let chars: Vec<char> = text.chars().collect();
         for i in 0..=chars.len() - self.ngram_size {
             let ngram: String = chars[i..i + self.ngram_size].iter().collect();
             *ngrams.entry(ngram).or_insert(0) += 1;
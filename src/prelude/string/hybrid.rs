@@ -0,0 +1,231 @@
+use {
+    crate::{
+        assessor::{Resembler, Resemblance},
+        prelude::{
+            sequence::{Elements, Pairing},
+            string::{
+                utils::{segments, Segment},
+                Corpus, Words,
+            },
+        },
+    },
+};
+
+/// Each query word scored against its best-matching candidate word, averaged
+pub struct MongeElkan<'hybrid> {
+    pub resembler: &'hybrid mut dyn Resembler<String, String, ()>,
+    words: Words,
+    symmetric: bool,
+}
+
+impl<'hybrid> MongeElkan<'hybrid> {
+    pub fn new<R: Resembler<String, String, ()> + 'hybrid>(resembler: &'hybrid mut R) -> Self {
+        Self { resembler, words: Words::default(), symmetric: false }
+    }
+
+    /// Tokenizer settings used to split both strings into words.
+    pub fn words(mut self, words: Words) -> Self {
+        self.words = words;
+        self
+    }
+
+    /// Averages both directions, since plain Monge–Elkan only asks how well the query is covered.
+    pub fn symmetric(mut self, symmetric: bool) -> Self {
+        self.symmetric = symmetric;
+        self
+    }
+
+    fn compute_resemblance(&mut self, query: &str, candidate: &str) -> Result<f64, ()> {
+        let query_words = self.words.extract_words(query);
+        let candidate_words = self.words.extract_words(candidate);
+
+        let mut elements = Elements { resembler: &mut *self.resembler, pairing: Pairing::MongeElkan };
+        let forward = elements.compute_resemblance(&query_words, &candidate_words)?;
+        if !self.symmetric {
+            return Ok(forward);
+        }
+
+        let backward = elements.compute_resemblance(&candidate_words, &query_words)?;
+        Ok((forward + backward) / 2.0)
+    }
+}
+
+impl Resembler<String, String, ()> for MongeElkan<'_> {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate)?;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+/// TF-IDF cosine where terms count as shared when an inner resembler scores them above a threshold
+pub struct SoftTfidf<'hybrid> {
+    pub resembler: &'hybrid mut dyn Resembler<String, String, ()>,
+    corpus: &'hybrid Corpus,
+    threshold: f64,
+}
+
+impl<'hybrid> SoftTfidf<'hybrid> {
+    pub fn new<R: Resembler<String, String, ()> + 'hybrid>(resembler: &'hybrid mut R, corpus: &'hybrid Corpus, threshold: f64) -> Self {
+        Self { resembler, corpus, threshold }
+    }
+
+    fn compute_resemblance(&mut self, query: &str, candidate: &str) -> Result<f64, ()> {
+        let query_weights = self.corpus.transform(query);
+        let candidate_weights = self.corpus.transform(candidate);
+
+        if query_weights.is_empty() && candidate_weights.is_empty() {
+            return Ok(1.0);
+        }
+        if query_weights.is_empty() || candidate_weights.is_empty() {
+            return Ok(0.0);
+        }
+
+        let mut score = 0.0;
+        for (query_word, query_weight) in &query_weights {
+            let mut best: Option<(f64, f64)> = None;
+            for (candidate_word, candidate_weight) in &candidate_weights {
+                let similarity = self.resembler.resemblance(query_word, candidate_word)?.to_f64();
+                if similarity >= self.threshold && best.is_none_or(|(existing, _)| similarity > existing) {
+                    best = Some((similarity, *candidate_weight));
+                }
+            }
+
+            if let Some((similarity, candidate_weight)) = best {
+                score += query_weight * candidate_weight * similarity;
+            }
+        }
+
+        Ok(score)
+    }
+}
+
+impl Resembler<String, String, ()> for SoftTfidf<'_> {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate)?;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+/// Text compared by an inner resembler and the numbers inside it compared by value, so
+/// `iPhone 13` and `iPhone 14` no longer pass on their shared letters
+pub struct Alphanumeric<'hybrid> {
+    pub resembler: &'hybrid mut dyn Resembler<String, String, ()>,
+    tolerance: f64,
+    weight: f64,
+    veto: bool,
+    decimals: bool,
+}
+
+impl<'hybrid> Alphanumeric<'hybrid> {
+    pub fn new<R: Resembler<String, String, ()> + 'hybrid>(resembler: &'hybrid mut R) -> Self {
+        Self { resembler, tolerance: 0.0, weight: 0.5, veto: false, decimals: false }
+    }
+
+    /// Absolute difference at which two numbers stop resembling each other, decaying linearly; 0 asks for equality.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance.abs();
+        self
+    }
+
+    /// Share of the score the numbers carry when both strings also hold text.
+    pub fn weight(mut self, weight: f64) -> Self {
+        self.weight = weight.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Makes any number outside the tolerance, or a differing count of numbers, a disparity.
+    pub fn veto(mut self, veto: bool) -> Self {
+        self.veto = veto;
+        self
+    }
+
+    /// Reads `3.14` as one number instead of the two a version string would hold.
+    pub fn decimals(mut self, decimals: bool) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    fn split(&self, text: &str) -> (String, Vec<f64>) {
+        let mut words = Vec::new();
+        let mut numbers = Vec::new();
+        for segment in segments(text, self.decimals) {
+            match segment {
+                Segment::Text(word) => words.push(word),
+                Segment::Number(digits) => numbers.push(digits.parse::<f64>().unwrap_or(f64::NAN)),
+            }
+        }
+
+        (words.join(" "), numbers)
+    }
+
+    fn compare_numbers(&self, query: f64, candidate: f64) -> f64 {
+        if query == candidate {
+            1.0
+        } else if self.tolerance == 0.0 {
+            0.0
+        } else {
+            (1.0 - (query - candidate).abs() / self.tolerance).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Numbers paired by position, a number without a counterpart scoring zero; `None` on a veto.
+    fn number_resemblance(&self, query: &[f64], candidate: &[f64]) -> Option<f64> {
+        let longest = query.len().max(candidate.len());
+        if longest == 0 {
+            return Some(1.0);
+        }
+        if self.veto && query.len() != candidate.len() {
+            return None;
+        }
+
+        let mut total = 0.0;
+        for (&first, &second) in query.iter().zip(candidate) {
+            let score = self.compare_numbers(first, second);
+            if self.veto && score == 0.0 {
+                return None;
+            }
+            total += score;
+        }
+
+        Some(total / longest as f64)
+    }
+
+    fn compute_resemblance(&mut self, query: &str, candidate: &str) -> Result<f64, ()> {
+        let (query_text, query_numbers) = self.split(query);
+        let (candidate_text, candidate_numbers) = self.split(candidate);
+
+        let numbers = match self.number_resemblance(&query_numbers, &candidate_numbers) {
+            Some(score) => score,
+            None => return Ok(0.0),
+        };
+
+        if query_numbers.is_empty() && candidate_numbers.is_empty() {
+            return Ok(self.resembler.resemblance(&query_text, &candidate_text)?.to_f64());
+        }
+        if query_text.is_empty() && candidate_text.is_empty() {
+            return Ok(numbers);
+        }
+
+        let text = self.resembler.resemblance(&query_text, &candidate_text)?.to_f64();
+        Ok(text * (1.0 - self.weight) + numbers * self.weight)
+    }
+}
+
+impl Resembler<String, String, ()> for Alphanumeric<'_> {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate)?;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
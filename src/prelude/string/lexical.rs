@@ -1,27 +1,42 @@
 use {
     crate::{
         assessor::{Resembler, Resemblance},
+        index::storage::fnv1a,
+        prelude::{
+            language::{stop_words, Porter2, Stemmer, Tokenizer, Whitespace},
+            set::Jaccard,
+        },
     },
     core::cmp::max,
     hashish::{HashSet},
 };
 
-#[derive(PartialEq)]
 pub struct Words {
     ignore_case: bool,
     min_word_len: usize,
-    separators: Option<Vec<char>>,
-    use_stemming: bool,
+    tokenizer: Box<dyn Tokenizer>,
+    stemmer: Option<Box<dyn Stemmer>>,
     stop_words: HashSet<String>,
 }
 
+/// Tokenizers and stemmers compare by their ids.
+impl PartialEq for Words {
+    fn eq(&self, other: &Self) -> bool {
+        self.ignore_case == other.ignore_case
+            && self.min_word_len == other.min_word_len
+            && self.stop_words == other.stop_words
+            && self.tokenizer.id() == other.tokenizer.id()
+            && self.stemmer.as_ref().map(|stemmer| stemmer.id()) == other.stemmer.as_ref().map(|stemmer| stemmer.id())
+    }
+}
+
 impl Default for Words {
     fn default() -> Self {
         Self {
             ignore_case: true,
             min_word_len: 1,
-            separators: None,
-            use_stemming: false,
+            tokenizer: Box::new(Whitespace::new()),
+            stemmer: None,
             stop_words: HashSet::new(),
         }
     }
@@ -38,8 +53,8 @@ impl Words {
         Self {
             ignore_case,
             min_word_len,
-            separators,
-            use_stemming,
+            tokenizer: Box::new(Whitespace::new().separators(separators.unwrap_or_default())),
+            stemmer: use_stemming.then(|| Box::new(Porter2) as Box<dyn Stemmer>),
             stop_words: stop_words.map(|words| words.into_iter().map(String::from).collect()).unwrap_or_default(),
         }
     }
@@ -48,43 +63,86 @@ impl Words {
         Self { ignore_case, ..Default::default() }
     }
 
-    fn extract_words(&self, text: &str) -> Vec<String> {
-        let normalized = if self.ignore_case { text.to_lowercase() } else { text.to_string() };
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    pub fn min_word_len(mut self, min_word_len: usize) -> Self {
+        self.min_word_len = min_word_len;
+        self
+    }
+
+    /// How text is split into words; see [`Unicode`](crate::language::Unicode) and friends.
+    pub fn tokenizer<T: Tokenizer + 'static>(mut self, tokenizer: T) -> Self {
+        self.tokenizer = Box::new(tokenizer);
+        self
+    }
+
+    /// Stems or lemmatizes every word before comparison; see [`Snowball`](crate::language::Snowball).
+    pub fn stemmer<S: Stemmer + 'static>(mut self, stemmer: S) -> Self {
+        self.stemmer = Some(Box::new(stemmer));
+        self
+    }
+
+    pub fn stop_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.stop_words.extend(words.into_iter().map(|word| word.as_ref().to_string()));
+        self
+    }
+
+    /// Adds the bundled stop words for an ISO 639-1 code; unknown codes add nothing.
+    pub fn stop_words_for(self, code: &str) -> Self {
+        self.stop_words(stop_words(code).unwrap_or_default())
+    }
+
+    /// Stable hash of the settings: case, minimum length, stop words, and the tokenizer and
+    /// stemmer ids.
+    pub(crate) fn fingerprint(&self) -> u64 {
+        let mut stop_words: Vec<&String> = self.stop_words.iter().collect();
+        stop_words.sort();
+
+        let mut bytes = vec![self.ignore_case as u8];
+        bytes.extend_from_slice(&(self.min_word_len as u64).to_le_bytes());
+        for word in stop_words {
+            bytes.extend_from_slice(word.as_bytes());
+            bytes.push(0);
+        }
+        // 0xff never occurs in UTF-8, so it cleanly ends the stop words.
+        bytes.push(0xff);
+        bytes.extend_from_slice(self.tokenizer.id().as_bytes());
+        bytes.push(0);
+        if let Some(stemmer) = &self.stemmer {
+            bytes.extend_from_slice(stemmer.id().as_bytes());
+        }
+
+        fnv1a(bytes)
+    }
+
+    pub(crate) fn extract_words(&self, text: &str) -> Vec<String> {
         let mut words = Vec::new();
-        let mut current = String::new();
-
-        for c in normalized.chars() {
-            let is_separator = c.is_whitespace() || self.separators.as_ref().map_or(false, |seps| seps.contains(&c));
-            if is_separator {
-                if !current.is_empty() {
-                    self.process_word(&current, &mut words);
-                    current.clear();
-                }
+        for token in self.tokenizer.tokenize(text) {
+            if self.ignore_case {
+                self.process_word(&token.to_lowercase(), &mut words);
             } else {
-                current.push(c);
+                self.process_word(token, &mut words);
             }
         }
-        if !current.is_empty() { self.process_word(&current, &mut words); }
         words
     }
 
     fn process_word(&self, word: &str, words: &mut Vec<String>) {
         if word.len() < self.min_word_len || self.stop_words.contains(word) { return; }
-        let processed = if self.use_stemming { self.stem_word(word) } else { word.to_string() };
+        let processed = match &self.stemmer {
+            Some(stemmer) => stemmer.stem(word),
+            None => word.to_string(),
+        };
         words.push(processed);
     }
 
-    fn stem_word(&self, word: &str) -> String {
-        let mut result = word.to_string();
-        for suffix in &["ing", "ed", "s", "es", "ies"] {
-            if result.ends_with(suffix) && result.len() > suffix.len() + 2 {
-                result.truncate(result.len() - suffix.len());
-                break;
-            }
-        }
-        result
-    }
-
     fn weighted_jaccard(&self, query_words: &[String], candidate_words: &[String]) -> f64 {
         if query_words.is_empty() && candidate_words.is_empty() { return 1.0; }
         if query_words.is_empty() || candidate_words.is_empty() { return 0.0; }
@@ -122,9 +180,7 @@ impl Resembler<String, String, ()> for Words {
         }
 
         let score = if query_words.len() <= 2 || candidate_words.len() <= 2 {
-            let common_words = query_words.iter().filter(|w| candidate_words.contains(w)).count();
-            let union_size = query_words.len() + candidate_words.len() - common_words;
-            common_words as f64 / union_size as f64
+            Jaccard::new(true).compute_resemblance(&query_words, &candidate_words)
         } else {
             self.weighted_jaccard(&query_words, &candidate_words)
         };
@@ -6,6 +6,10 @@ pub mod phonetic;
 pub mod structural;
 pub mod lexical;
 pub mod proximity;
+pub mod hybrid;
+pub mod corpus;
+pub mod weighted;
+pub mod path;
 mod format;
 
 pub use exact::*;
@@ -13,4 +17,8 @@ pub use fuzzy::*;
 pub use phonetic::*;
 pub use structural::*;
 pub use lexical::*;
-pub use proximity::*;
\ No newline at end of file
+pub use proximity::*;
+pub use hybrid::*;
+pub use corpus::*;
+pub use weighted::*;
+pub use path::*;
\ No newline at end of file
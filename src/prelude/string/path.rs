@@ -0,0 +1,170 @@
+use crate::{
+    assessor::{Resembler, Resemblance},
+    prelude::string::utils::glob,
+};
+
+/// A path split into its directories, the basename's stem and its extension.
+struct Parts<'text> {
+    directories: Vec<&'text str>,
+    stem: &'text str,
+    extension: Option<&'text str>,
+}
+
+impl<'text> Parts<'text> {
+    /// Splits on `/` and `\`, skipping empty and `.` segments; a leading dot, as in `.gitignore`,
+    /// is part of the stem rather than an extension.
+    fn new(path: &'text str) -> Self {
+        let mut directories: Vec<&str> = path.split(['/', '\\'])
+            .filter(|segment| !segment.is_empty() && *segment != ".")
+            .collect();
+        let basename = directories.pop().unwrap_or("");
+
+        let (stem, extension) = match basename.rfind('.') {
+            Some(position) if position > 0 => (&basename[..position], Some(&basename[position + 1..])),
+            _ => (basename, None),
+        };
+
+        Self { directories, stem, extension }
+    }
+}
+
+/// File path matching that weights the basename most, scores directories in order and compares
+/// extensions apart, with any segment-level string resembler scoring the names
+pub struct Path<'path> {
+    pub resembler: &'path mut dyn Resembler<String, String, ()>,
+    basename: f64,
+    extension: f64,
+    directories: f64,
+    threshold: f64,
+    glob: bool,
+}
+
+impl<'path> Path<'path> {
+    pub fn new<R: Resembler<String, String, ()> + 'path>(resembler: &'path mut R) -> Self {
+        Self { resembler, basename: 0.6, extension: 0.1, directories: 0.3, threshold: 0.5, glob: true }
+    }
+
+    /// Relative weights of the basename's stem, the extension and the directories.
+    ///
+    /// A part the query leaves out, such as the directories of a bare `main.rs`, drops out of
+    /// the score rather than counting against every candidate.
+    pub fn weights(mut self, basename: f64, extension: f64, directories: f64) -> Self {
+        self.basename = basename.max(0.0);
+        self.extension = extension.max(0.0);
+        self.directories = directories.max(0.0);
+        self
+    }
+
+    /// Lowest segment score that counts as a match when pairing directories.
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Whether a query holding `*`, `?` or `[` is tried as a glob first, a match being perfect.
+    pub fn glob(mut self, glob: bool) -> Self {
+        self.glob = glob;
+        self
+    }
+
+    /// Whether the query is a glob matching the candidate; like `.gitignore` entries, a pattern
+    /// without a separator is tried against the basename alone.
+    fn is_match(&self, query: &str, candidate: &str) -> bool {
+        if !self.glob || !query.contains(['*', '?', '[']) {
+            return false;
+        }
+
+        let candidate = candidate.replace('\\', "/");
+        let pattern = query.replace('\\', "/");
+        if pattern.contains('/') {
+            glob(&pattern, &candidate)
+        } else {
+            glob(&pattern, candidate.rsplit('/').next().unwrap_or(""))
+        }
+    }
+
+    /// Pairs query directories with candidate directories in order, maximising their total
+    /// score, returning that total and the paired indices.
+    fn pair_directories(&mut self, query: &[&str], candidate: &[&str]) -> Result<(f64, Vec<(usize, usize)>), ()> {
+        let mut scores = vec![vec![0.0; candidate.len()]; query.len()];
+        for (i, query_segment) in query.iter().enumerate() {
+            for (j, candidate_segment) in candidate.iter().enumerate() {
+                let score = self.resembler.resemblance(&query_segment.to_string(), &candidate_segment.to_string())?.to_f64();
+                scores[i][j] = if score >= self.threshold { score } else { 0.0 };
+            }
+        }
+
+        let mut table = vec![vec![0.0; candidate.len() + 1]; query.len() + 1];
+        for i in 1..=query.len() {
+            for j in 1..=candidate.len() {
+                table[i][j] = (table[i - 1][j - 1] + scores[i - 1][j - 1])
+                    .max(table[i - 1][j])
+                    .max(table[i][j - 1]);
+            }
+        }
+
+        let (mut i, mut j) = (query.len(), candidate.len());
+        let mut pairs = Vec::new();
+        while i > 0 && j > 0 {
+            if scores[i - 1][j - 1] > 0.0 && table[i][j] == table[i - 1][j - 1] + scores[i - 1][j - 1] {
+                pairs.push((i - 1, j - 1));
+                i -= 1;
+                j -= 1;
+            } else if table[i][j] == table[i - 1][j] {
+                i -= 1;
+            } else {
+                j -= 1;
+            }
+        }
+        pairs.reverse();
+
+        Ok((table[query.len()][candidate.len()], pairs))
+    }
+
+    /// Score with the matched segments, as `(query, candidate)` indices among each path's
+    /// segments, the basename being the last.
+    fn compute(&mut self, query: &str, candidate: &str) -> Result<(f64, Vec<(usize, usize)>), ()> {
+        let query_parts = Parts::new(query);
+        let candidate_parts = Parts::new(candidate);
+
+        let (directory_total, mut pairs) = self.pair_directories(&query_parts.directories, &candidate_parts.directories)?;
+        let stem = self.resembler.resemblance(&query_parts.stem.to_string(), &candidate_parts.stem.to_string())?.to_f64();
+        if stem >= self.threshold {
+            pairs.push((query_parts.directories.len(), candidate_parts.directories.len()));
+        }
+
+        let mut score = self.basename * stem;
+        let mut weight = self.basename;
+
+        if let Some(query_extension) = query_parts.extension {
+            let same = candidate_parts.extension.is_some_and(|extension| extension.eq_ignore_ascii_case(query_extension));
+            score += if same { self.extension } else { 0.0 };
+            weight += self.extension;
+        }
+
+        if !query_parts.directories.is_empty() {
+            score += self.directories * directory_total / query_parts.directories.len() as f64;
+            weight += self.directories;
+        }
+
+        let score = if weight > 0.0 { score / weight } else { stem };
+        Ok((score, pairs))
+    }
+
+    /// Indices of the query's segments paired with the candidate's, the basename being the
+    /// last segment of each; `None` when the segment resembler fails.
+    pub fn matched(&mut self, query: &str, candidate: &str) -> Option<Vec<(usize, usize)>> {
+        self.compute(query, candidate).ok().map(|(_, pairs)| pairs)
+    }
+}
+
+impl Resembler<String, String, ()> for Path<'_> {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate || self.is_match(query, candidate) {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let (score, _) = self.compute(query, candidate)?;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
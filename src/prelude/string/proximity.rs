@@ -2,10 +2,10 @@ use {
     hashish::HashMap,
     crate::{
         assessor::{Resembler, Resemblance},
-        prelude::string::utils::{edit_distance, keyboard::Layout},
+        prelude::string::utils::{alignment, keyboard::Layout},
     }
 };
-use core::cmp::{max, min};
+use core::cmp::max;
 
 /// Keyboard proximity matching
 #[derive(PartialEq)]
@@ -42,19 +42,18 @@ impl Resembler<String, String, ()> for Keyboard {
             return Ok(Resemblance::Disparity);
         }
 
-        let distance = edit_distance(query, candidate);
+        let alignment = alignment(query, candidate);
+        let distance = alignment.distance;
         if distance > 3 {
             return Ok(Resemblance::Disparity);
         }
 
-        let mut adjacent_count = 0;
-        let max_comparisons = min(query_chars.len(), candidate_chars.len());
-        for i in 0..max_comparisons {
-            if query_chars[i] == candidate_chars[i] { continue; }
-            if let Some(neighbors) = self.layout.get(&query_chars[i]) {
-                if neighbors.contains(&candidate_chars[i]) { adjacent_count += 1; }
-            }
-        }
+        let adjacent_count = alignment.substitutions()
+            .filter(|(typed, intended)| {
+                self.layout.get(&typed.to_ascii_lowercase())
+                    .is_some_and(|neighbors| neighbors.contains(&intended.to_ascii_lowercase()))
+            })
+            .count();
 
         let differing_chars = distance;
         if differing_chars == 0 { return Ok(Resemblance::Perfect); }
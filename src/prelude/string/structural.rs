@@ -1,5 +1,11 @@
+use hashish::HashMap;
+
 use crate::{
     assessor::{Resembler, Resemblance},
+    prelude::{
+        set::Dice,
+        string::utils::{boundaries, runs, same_letter, table, Span, Substitution, Uniform},
+    },
 };
 
 /// Prefix matching
@@ -77,9 +83,9 @@ impl Sequential {
     }
 
     fn generate_ngrams(&self, text: &str) -> Vec<String> {
-        if text.len() < self.size { return vec![text.to_string()]; }
-
         let chars: Vec<char> = text.chars().collect();
+        if chars.len() < self.size { return vec![text.to_string()]; }
+
         (0..=chars.len() - self.size)
             .map(|i| chars[i..i + self.size].iter().collect())
             .collect()
@@ -106,8 +112,7 @@ impl Resembler<String, String, ()> for Sequential {
             return Ok(Resemblance::Disparity);
         }
 
-        let intersection = query_ngrams.iter().filter(|ngram| candidate_ngrams.contains(ngram)).count();
-        let score = 2.0 * intersection as f64 / (query_ngrams.len() + candidate_ngrams.len()) as f64;
+        let score = Dice::new(true).compute_resemblance(&query_ngrams, &candidate_ngrams);
 
         let result = if score >= 1.0 {
             Resemblance::Perfect
@@ -119,4 +124,507 @@ impl Resembler<String, String, ()> for Sequential {
 
         Ok(result)
     }
-}
\ No newline at end of file
+}
+
+/// Local alignment (Smith–Waterman with affine gaps), finding the query inside the candidate despite typos
+#[derive(PartialEq)]
+pub struct Local {
+    reward: f64,
+    mismatch: f64,
+    gap_open: f64,
+    gap_extend: f64,
+}
+
+impl Default for Local {
+    fn default() -> Self {
+        Self { reward: 2.0, mismatch: 1.0, gap_open: 2.0, gap_extend: 1.0 }
+    }
+}
+
+impl Local {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Score gained for each matching character.
+    pub fn reward(mut self, reward: f64) -> Self {
+        self.reward = reward.max(f64::EPSILON);
+        self
+    }
+
+    /// Cost of aligning two different characters.
+    pub fn mismatch(mut self, mismatch: f64) -> Self {
+        self.mismatch = mismatch.max(0.0);
+        self
+    }
+
+    /// Cost of the first character of a gap and of each one after it.
+    pub fn gap(mut self, open: f64, extend: f64) -> Self {
+        self.gap_open = open.max(0.0);
+        self.gap_extend = extend.max(0.0);
+        self
+    }
+
+    /// The best-scoring local alignment, ignoring case, if any characters align at all.
+    pub fn locate(&self, query: &str, candidate: &str) -> Option<Span> {
+        let query_chars: Vec<char> = query.chars().collect();
+        let candidate_chars: Vec<char> = candidate.chars().collect();
+        let width = candidate_chars.len() + 1;
+
+        // Each cell keeps its score and the (query, candidate) position its alignment starts at.
+        let empty = (f64::NEG_INFINITY, (0, 0));
+        let mut best = (0.0, (0, 0), (0, 0));
+        let mut previous_h: Vec<(f64, (usize, usize))> = (0..width).map(|j| (0.0, (0, j))).collect();
+        let mut previous_f = vec![empty; width];
+
+        for i in 1..=query_chars.len() {
+            let mut h = vec![(0.0, (i, 0)); width];
+            let mut f = vec![empty; width];
+            let mut e = empty;
+
+            for j in 1..width {
+                let opened = (h[j - 1].0 - self.gap_open, h[j - 1].1);
+                let extended = (e.0 - self.gap_extend, e.1);
+                e = if extended.0 > opened.0 { extended } else { opened };
+
+                let opened = (previous_h[j].0 - self.gap_open, previous_h[j].1);
+                let extended = (previous_f[j].0 - self.gap_extend, previous_f[j].1);
+                f[j] = if extended.0 > opened.0 { extended } else { opened };
+
+                let substitution = if same_letter(query_chars[i - 1], candidate_chars[j - 1]) { self.reward } else { -self.mismatch };
+                let diagonal = (previous_h[j - 1].0 + substitution, previous_h[j - 1].1);
+
+                let mut cell = (0.0, (i, j));
+                for option in [diagonal, e, f[j]] {
+                    if option.0 > cell.0 {
+                        cell = option;
+                    }
+                }
+                h[j] = cell;
+
+                if cell.0 > best.0 {
+                    best = (cell.0, cell.1, (i, j));
+                }
+            }
+
+            previous_h = h;
+            previous_f = f;
+        }
+
+        let (score, (query_start, candidate_start), (query_end, candidate_end)) = best;
+        if score <= 0.0 {
+            return None;
+        }
+
+        let query_offsets = boundaries(query);
+        let candidate_offsets = boundaries(candidate);
+        Some(Span {
+            query: query_offsets[query_start]..query_offsets[query_end],
+            candidate: candidate_offsets[candidate_start]..candidate_offsets[candidate_end],
+            score,
+        })
+    }
+
+    /// Local score relative to the whole query matching perfectly.
+    fn compute_resemblance(&self, query: &str, candidate: &str) -> f64 {
+        let ideal = query.chars().count() as f64 * self.reward;
+        match self.locate(query, candidate) {
+            Some(span) if ideal > 0.0 => span.score / ideal,
+            _ => 0.0,
+        }
+    }
+}
+
+impl Resembler<String, String, ()> for Local {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+/// Longest common subsequence length, ignoring case
+#[derive(PartialEq)]
+pub struct Subsequence;
+
+impl Subsequence {
+    /// The common subsequence, as runs of characters aligned in both strings.
+    pub fn spans(&self, query: &str, candidate: &str) -> Vec<Span> {
+        let query_chars: Vec<char> = query.chars().collect();
+        let candidate_chars: Vec<char> = candidate.chars().collect();
+        let matrix = table(&query_chars, &candidate_chars, |_, _| 0usize, |matrix, i, j| {
+            if same_letter(query_chars[i - 1], candidate_chars[j - 1]) {
+                matrix[i - 1][j - 1] + 1
+            } else {
+                matrix[i - 1][j].max(matrix[i][j - 1])
+            }
+        });
+
+        let (mut i, mut j) = (query_chars.len(), candidate_chars.len());
+        let mut pairs = Vec::new();
+        while i > 0 && j > 0 {
+            if same_letter(query_chars[i - 1], candidate_chars[j - 1]) && matrix[i][j] == matrix[i - 1][j - 1] + 1 {
+                pairs.push((i - 1, j - 1));
+                i -= 1;
+                j -= 1;
+            } else if matrix[i - 1][j] >= matrix[i][j - 1] {
+                i -= 1;
+            } else {
+                j -= 1;
+            }
+        }
+        pairs.reverse();
+
+        runs(query, candidate, &pairs, |_, _| 1.0)
+    }
+
+    fn compute_resemblance(&self, query: &str, candidate: &str) -> f64 {
+        let total = query.chars().count() + candidate.chars().count();
+        if total == 0 {
+            return 1.0;
+        }
+
+        let common: f64 = self.spans(query, candidate).iter().map(|span| span.score).sum();
+        2.0 * common / total as f64
+    }
+}
+
+impl Resembler<String, String, ()> for Subsequence {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+/// Longest common substring, ignoring case
+#[derive(PartialEq)]
+pub struct Substring;
+
+impl Substring {
+    /// The longest run of characters the two strings share, earliest in the query on ties.
+    pub fn spans(&self, query: &str, candidate: &str) -> Vec<Span> {
+        let query_chars: Vec<char> = query.chars().collect();
+        let candidate_chars: Vec<char> = candidate.chars().collect();
+        let matrix = table(&query_chars, &candidate_chars, |_, _| 0usize, |matrix, i, j| {
+            if same_letter(query_chars[i - 1], candidate_chars[j - 1]) { matrix[i - 1][j - 1] + 1 } else { 0 }
+        });
+
+        let mut best = (0, 0, 0);
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, &length) in row.iter().enumerate() {
+                if length > best.0 {
+                    best = (length, i, j);
+                }
+            }
+        }
+
+        let (length, i, j) = best;
+        let pairs: Vec<(usize, usize)> = (0..length).map(|offset| (i - length + offset, j - length + offset)).collect();
+        runs(query, candidate, &pairs, |_, _| 1.0)
+    }
+
+    fn compute_resemblance(&self, query: &str, candidate: &str) -> f64 {
+        let total = query.chars().count() + candidate.chars().count();
+        if total == 0 {
+            return 1.0;
+        }
+
+        let longest = self.spans(query, candidate).first().map_or(0.0, |span| span.score);
+        2.0 * longest / total as f64
+    }
+}
+
+impl Resembler<String, String, ()> for Substring {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+/// Ratcliff/Obershelp pattern matching, scoring as Python's `difflib.SequenceMatcher.ratio()`
+#[derive(PartialEq)]
+pub struct Ratcliff {
+    autojunk: bool,
+}
+
+impl Default for Ratcliff {
+    fn default() -> Self {
+        Self { autojunk: true }
+    }
+}
+
+impl Ratcliff {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether characters filling more than 1% of a candidate of 200 or more characters stop
+    /// seeding matches, as difflib does by default.
+    pub fn autojunk(mut self, autojunk: bool) -> Self {
+        self.autojunk = autojunk;
+        self
+    }
+
+    /// Longest match within the given char ranges, with difflib's tie-breaking: earliest in
+    /// the query, then earliest in the candidate.
+    fn longest_match(
+        query: &[char],
+        candidate: &[char],
+        positions: &HashMap<char, Vec<usize>>,
+        (query_start, query_end): (usize, usize),
+        (candidate_start, candidate_end): (usize, usize),
+    ) -> (usize, usize, usize) {
+        let (mut best_i, mut best_j, mut best_size) = (query_start, candidate_start, 0);
+        let mut lengths: HashMap<usize, usize> = HashMap::new();
+
+        for (i, c) in query.iter().enumerate().take(query_end).skip(query_start) {
+            let mut next: HashMap<usize, usize> = HashMap::new();
+            for &j in positions.get(c).map_or(&[][..], |found| found.as_slice()) {
+                if j < candidate_start {
+                    continue;
+                }
+                if j >= candidate_end {
+                    break;
+                }
+
+                let length = j.checked_sub(1).and_then(|previous| lengths.get(&previous)).copied().unwrap_or(0) + 1;
+                next.insert(j, length);
+                if length > best_size {
+                    best_i = i + 1 - length;
+                    best_j = j + 1 - length;
+                    best_size = length;
+                }
+            }
+            lengths = next;
+        }
+
+        // Popular characters never seed a match, but may still extend one.
+        while best_i > query_start && best_j > candidate_start && query[best_i - 1] == candidate[best_j - 1] {
+            best_i -= 1;
+            best_j -= 1;
+            best_size += 1;
+        }
+        while best_i + best_size < query_end
+            && best_j + best_size < candidate_end
+            && query[best_i + best_size] == candidate[best_j + best_size]
+        {
+            best_size += 1;
+        }
+
+        (best_i, best_j, best_size)
+    }
+
+    /// Matching blocks as `(query index, candidate index, length)` in chars, ordered by position.
+    fn blocks(&self, query: &[char], candidate: &[char]) -> Vec<(usize, usize, usize)> {
+        let mut positions: HashMap<char, Vec<usize>> = HashMap::new();
+        for (j, &c) in candidate.iter().enumerate() {
+            positions.entry(c).or_default().push(j);
+        }
+        if self.autojunk && candidate.len() >= 200 {
+            let limit = candidate.len() / 100 + 1;
+            positions.retain(|_, found| found.len() <= limit);
+        }
+
+        let mut queue = vec![(0, query.len(), 0, candidate.len())];
+        let mut blocks = Vec::new();
+        while let Some((query_start, query_end, candidate_start, candidate_end)) = queue.pop() {
+            let (i, j, size) = Self::longest_match(
+                query,
+                candidate,
+                &positions,
+                (query_start, query_end),
+                (candidate_start, candidate_end),
+            );
+            if size == 0 {
+                continue;
+            }
+
+            blocks.push((i, j, size));
+            if query_start < i && candidate_start < j {
+                queue.push((query_start, i, candidate_start, j));
+            }
+            if i + size < query_end && j + size < candidate_end {
+                queue.push((i + size, query_end, j + size, candidate_end));
+            }
+        }
+
+        blocks.sort_unstable();
+        blocks
+    }
+
+    /// The matching blocks, each scored by its length in characters.
+    pub fn spans(&self, query: &str, candidate: &str) -> Vec<Span> {
+        let query_chars: Vec<char> = query.chars().collect();
+        let candidate_chars: Vec<char> = candidate.chars().collect();
+        let pairs: Vec<(usize, usize)> = self.blocks(&query_chars, &candidate_chars)
+            .into_iter()
+            .flat_map(|(i, j, size)| (0..size).map(move |offset| (i + offset, j + offset)))
+            .collect();
+
+        runs(query, candidate, &pairs, |_, _| 1.0)
+    }
+
+    fn compute_resemblance(&self, query: &str, candidate: &str) -> f64 {
+        let query_chars: Vec<char> = query.chars().collect();
+        let candidate_chars: Vec<char> = candidate.chars().collect();
+        let total = query_chars.len() + candidate_chars.len();
+        if total == 0 {
+            return 1.0;
+        }
+
+        let matched: usize = self.blocks(&query_chars, &candidate_chars).iter().map(|&(_, _, size)| size).sum();
+        2.0 * matched as f64 / total as f64
+    }
+}
+
+impl Resembler<String, String, ()> for Ratcliff {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    Aligned,
+    Deleted,
+    Inserted,
+}
+
+/// The highest of the scores ending aligned, deleted and inserted, preferring them in that order on ties.
+fn best(scores: [f64; 3]) -> (f64, State) {
+    let mut chosen = (scores[0], State::Aligned);
+    for (score, state) in [(scores[1], State::Deleted), (scores[2], State::Inserted)] {
+        if score > chosen.0 {
+            chosen = (score, state);
+        }
+    }
+    chosen
+}
+
+/// Global alignment (Needleman–Wunsch with affine gaps) over a pluggable substitution matrix
+pub struct Global {
+    substitution: Box<dyn Substitution>,
+    gap_open: f64,
+    gap_extend: f64,
+}
+
+impl Default for Global {
+    fn default() -> Self {
+        Self { substitution: Box::new(Uniform::default()), gap_open: 2.0, gap_extend: 1.0 }
+    }
+}
+
+impl Global {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn substitution<S: Substitution + 'static>(mut self, substitution: S) -> Self {
+        self.substitution = Box::new(substitution);
+        self
+    }
+
+    /// Cost of the first character of a gap and of each one after it.
+    pub fn gap(mut self, open: f64, extend: f64) -> Self {
+        self.gap_open = open.max(0.0);
+        self.gap_extend = extend.max(0.0);
+        self
+    }
+
+    /// Best global alignment score with the char index pairs it aligns.
+    fn align(&self, query_chars: &[char], candidate_chars: &[char]) -> (f64, Vec<(usize, usize)>) {
+        let gap = |length: usize| -(self.gap_open + (length as f64 - 1.0) * self.gap_extend);
+        let none = (f64::NEG_INFINITY, State::Aligned);
+
+        // Each entry holds the best score ending aligned, in a deletion and in an insertion,
+        // with the state the alignment was in one step earlier.
+        let matrix = table(query_chars, candidate_chars, |i, j| match (i, j) {
+            (0, 0) => [(0.0, State::Aligned), none, none],
+            (i, 0) => [none, (gap(i), State::Deleted), none],
+            (0, j) => [none, none, (gap(j), State::Inserted)],
+            _ => [none; 3],
+        }, |matrix, i, j| {
+            let [aligned, deleted, inserted] = matrix[i - 1][j - 1];
+            let [up_aligned, up_deleted, up_inserted] = matrix[i - 1][j];
+            let [left_aligned, left_deleted, left_inserted] = matrix[i][j - 1];
+            let (score, from) = best([aligned.0, deleted.0, inserted.0]);
+
+            [
+                (score + self.substitution.score(query_chars[i - 1], candidate_chars[j - 1]), from),
+                best([up_aligned.0 - self.gap_open, up_deleted.0 - self.gap_extend, up_inserted.0 - self.gap_open]),
+                best([left_aligned.0 - self.gap_open, left_deleted.0 - self.gap_open, left_inserted.0 - self.gap_extend]),
+            ]
+        });
+
+        let (mut i, mut j) = (query_chars.len(), candidate_chars.len());
+        let last = matrix[i][j];
+        let (score, mut state) = best([last[0].0, last[1].0, last[2].0]);
+        let mut pairs = Vec::new();
+
+        while i > 0 || j > 0 {
+            let from = matrix[i][j][state as usize].1;
+            match state {
+                State::Aligned => {
+                    pairs.push((i - 1, j - 1));
+                    i -= 1;
+                    j -= 1;
+                }
+                State::Deleted => i -= 1,
+                State::Inserted => j -= 1,
+            }
+            state = from;
+        }
+        pairs.reverse();
+
+        (score, pairs)
+    }
+
+    /// Runs of characters aligned to each other, matched or substituted, scored by the substitution matrix.
+    pub fn spans(&self, query: &str, candidate: &str) -> Vec<Span> {
+        let query_chars: Vec<char> = query.chars().collect();
+        let candidate_chars: Vec<char> = candidate.chars().collect();
+        let (_, pairs) = self.align(&query_chars, &candidate_chars);
+        runs(query, candidate, &pairs, |i, j| self.substitution.score(query_chars[i], candidate_chars[j]))
+    }
+
+    /// Alignment score relative to the better of the two strings aligned with itself.
+    fn compute_resemblance(&self, query: &str, candidate: &str) -> f64 {
+        let query_chars: Vec<char> = query.chars().collect();
+        let candidate_chars: Vec<char> = candidate.chars().collect();
+        let ideal = |chars: &[char]| chars.iter().map(|&c| self.substitution.score(c, c)).sum::<f64>();
+
+        let best = ideal(&query_chars).max(ideal(&candidate_chars));
+        if best <= 0.0 {
+            return if query_chars.is_empty() && candidate_chars.is_empty() { 1.0 } else { 0.0 };
+        }
+
+        self.align(&query_chars, &candidate_chars).0 / best
+    }
+}
+
+impl Resembler<String, String, ()> for Global {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
@@ -1,8 +1,9 @@
 use {
     core::{
         cmp::{
-            min,
+            min, Ordering,
         },
+        ops::Range,
     },
 };
 
@@ -24,37 +25,419 @@ pub fn edit_distance(s1: &str, s2: &str) -> usize {
         return len_s1;
     }
 
-    let mut matrix = vec![vec![0; len_s2 + 1]; len_s1 + 1];
+    distance_matrix(&s1_chars, &s2_chars)[len_s1][len_s2]
+}
+
+/// Dynamic-programming table over two char sequences, shared by every alignment in this module:
+/// `border` seeds each entry, then `cell` recomputes every entry off row and column zero in
+/// row-major order, so it may read the entries above and to the left.
+pub(crate) fn table<T>(
+    s1_chars: &[char],
+    s2_chars: &[char],
+    border: impl Fn(usize, usize) -> T,
+    cell: impl Fn(&[Vec<T>], usize, usize) -> T,
+) -> Vec<Vec<T>> {
+    let mut matrix: Vec<Vec<T>> = (0..=s1_chars.len())
+        .map(|i| (0..=s2_chars.len()).map(|j| border(i, j)).collect())
+        .collect();
+
+    for i in 1..=s1_chars.len() {
+        for j in 1..=s2_chars.len() {
+            let value = cell(&matrix, i, j);
+            matrix[i][j] = value;
+        }
+    }
+
+    matrix
+}
+
+/// Restricted Damerau-Levenshtein DP matrix shared by `edit_distance` and `alignment`.
+fn distance_matrix(s1_chars: &[char], s2_chars: &[char]) -> Vec<Vec<usize>> {
+    table(s1_chars, s2_chars, |i, j| i + j, |matrix, i, j| {
+        let cost = if s1_chars[i - 1] == s2_chars[j - 1] { 0 } else { 1 };
+
+        let mut value = min(
+            matrix[i - 1][j] + 1,
+            min(
+                matrix[i][j - 1] + 1,
+                matrix[i - 1][j - 1] + cost
+            )
+        );
+
+        if i > 1 && j > 1 && s1_chars[i - 1] == s2_chars[j - 2] && s1_chars[i - 2] == s2_chars[j - 1] {
+            value = min(value, matrix[i - 2][j - 2] + cost);
+        }
+
+        value
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Match(char),
+    Substitute(char, char),
+    Insert(char),
+    Delete(char),
+    /// Two adjacent characters of the first string appearing swapped in the second.
+    Transpose(char, char),
+}
+
+/// The operations turning one string into another, as found by `edit_distance`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Alignment {
+    pub steps: Vec<Step>,
+    pub distance: usize,
+}
+
+pub fn alignment(s1: &str, s2: &str) -> Alignment {
+    let s1_chars: Vec<char> = s1.chars().collect();
+    let s2_chars: Vec<char> = s2.chars().collect();
+    let matrix = distance_matrix(&s1_chars, &s2_chars);
+
+    let (mut i, mut j) = (s1_chars.len(), s2_chars.len());
+    let mut steps = Vec::new();
+
+    while i > 0 || j > 0 {
+        let current = matrix[i][j];
+
+        if i > 0 && j > 0 && s1_chars[i - 1] == s2_chars[j - 1] && current == matrix[i - 1][j - 1] {
+            steps.push(Step::Match(s1_chars[i - 1]));
+            i -= 1;
+            j -= 1;
+        } else if i > 1 && j > 1
+            && s1_chars[i - 1] == s2_chars[j - 2]
+            && s1_chars[i - 2] == s2_chars[j - 1]
+            && current == matrix[i - 2][j - 2] + 1
+        {
+            steps.push(Step::Transpose(s1_chars[i - 2], s1_chars[i - 1]));
+            i -= 2;
+            j -= 2;
+        } else if i > 0 && j > 0 && current == matrix[i - 1][j - 1] + 1 {
+            steps.push(Step::Substitute(s1_chars[i - 1], s2_chars[j - 1]));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && current == matrix[i - 1][j] + 1 {
+            steps.push(Step::Delete(s1_chars[i - 1]));
+            i -= 1;
+        } else {
+            steps.push(Step::Insert(s2_chars[j - 1]));
+            j -= 1;
+        }
+    }
+
+    steps.reverse();
+    Alignment { steps, distance: matrix[s1_chars.len()][s2_chars.len()] }
+}
 
-    for i in 0..=len_s1 {
-        matrix[i][0] = i;
+impl Alignment {
+    pub fn substitutions(&self) -> impl Iterator<Item = (char, char)> + '_ {
+        self.steps.iter().filter_map(|step| match step {
+            Step::Substitute(from, to) => Some((*from, *to)),
+            _ => None,
+        })
     }
-    for j in 0..=len_s2 {
-        matrix[0][j] = j;
+
+    pub fn transpositions(&self) -> impl Iterator<Item = (char, char)> + '_ {
+        self.steps.iter().filter_map(|step| match step {
+            Step::Transpose(first, second) => Some((*first, *second)),
+            _ => None,
+        })
     }
 
-    for i in 1..=len_s1 {
-        for j in 1..=len_s2 {
-            let cost = if s1_chars[i - 1] == s2_chars[j - 1] { 0 } else { 1 };
+    /// Renders the alignment as an inline diff: `[-x]` deleted, `[+x]` inserted,
+    /// `[x→y]` substituted and `[xy⇄yx]` transposed, with runs of the same kind grouped.
+    pub fn render(&self) -> String {
+        let mut rendered = String::new();
+        let mut position = 0;
 
-            matrix[i][j] = min(
-                matrix[i - 1][j] + 1,
-                min(
-                    matrix[i][j - 1] + 1,
-                    matrix[i - 1][j - 1] + cost
-                )
-            );
+        while position < self.steps.len() {
+            let step = self.steps[position];
+            let run = self.steps[position..]
+                .iter()
+                .take_while(|next| core::mem::discriminant(*next) == core::mem::discriminant(&step))
+                .count();
+            let group = &self.steps[position..position + run];
 
-            if i > 1 && j > 1 && s1_chars[i - 1] == s2_chars[j - 2] && s1_chars[i - 2] == s2_chars[j - 1] {
-                matrix[i][j] = min(
-                    matrix[i][j],
-                    matrix[i - 2][j - 2] + cost
-                );
+            match step {
+                Step::Match(_) => {
+                    rendered.extend(group.iter().filter_map(|step| match step { Step::Match(c) => Some(*c), _ => None }));
+                }
+                Step::Delete(_) => {
+                    rendered.push_str("[-");
+                    rendered.extend(group.iter().filter_map(|step| match step { Step::Delete(c) => Some(*c), _ => None }));
+                    rendered.push(']');
+                }
+                Step::Insert(_) => {
+                    rendered.push_str("[+");
+                    rendered.extend(group.iter().filter_map(|step| match step { Step::Insert(c) => Some(*c), _ => None }));
+                    rendered.push(']');
+                }
+                Step::Substitute(_, _) => {
+                    rendered.push('[');
+                    rendered.extend(group.iter().filter_map(|step| match step { Step::Substitute(from, _) => Some(*from), _ => None }));
+                    rendered.push('→');
+                    rendered.extend(group.iter().filter_map(|step| match step { Step::Substitute(_, to) => Some(*to), _ => None }));
+                    rendered.push(']');
+                }
+                Step::Transpose(first, second) => {
+                    rendered.push_str(&format!("[{}{}⇄{}{}]", first, second, second, first));
+                    position += 1;
+                    continue;
+                }
             }
+
+            position += run;
         }
+
+        rendered
     }
+}
+
+impl core::fmt::Display for Alignment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+/// A run of digits or of everything between them
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Segment<'text> {
+    Number(&'text str),
+    Text(&'text str),
+}
+
+/// Splits text into digit runs and the text between them, trimmed, dropping text that holds no
+/// letters. With `decimals`, a period between digits stays inside the number, so `3.14` is one
+/// number rather than two as in version strings.
+pub fn segments<'text>(text: &'text str, decimals: bool) -> Vec<Segment<'text>> {
+    let characters: Vec<(usize, char)> = text.char_indices().collect();
+    let digit = |index: usize| characters.get(index).is_some_and(|&(_, c)| c.is_ascii_digit());
+    let offset = |index: usize| characters.get(index).map_or(text.len(), |&(position, _)| position);
+
+    let mut segments = Vec::new();
+    let push_text = |segments: &mut Vec<Segment<'text>>, start: usize, end: usize| {
+        let slice = &text[offset(start)..offset(end)];
+        if slice.chars().any(char::is_alphanumeric) {
+            segments.push(Segment::Text(slice.trim()));
+        }
+    };
+
+    let (mut start, mut index) = (0, 0);
+    while index < characters.len() {
+        if !digit(index) {
+            index += 1;
+            continue;
+        }
+
+        push_text(&mut segments, start, index);
+        let begin = index;
+        while digit(index) {
+            index += 1;
+        }
+        if decimals && characters.get(index).is_some_and(|&(_, c)| c == '.') && digit(index + 1) {
+            index += 1;
+            while digit(index) {
+                index += 1;
+            }
+        }
+        segments.push(Segment::Number(&text[offset(begin)..offset(index)]));
+        start = index;
+    }
+    push_text(&mut segments, start, index);
+
+    segments
+}
+
+/// Natural ordering, where digit runs compare by value: `file2` sorts before `file10`.
+pub fn natural(first: &str, second: &str) -> Ordering {
+    let value = |digits: &str| {
+        let trimmed = digits.trim_start_matches('0');
+        (trimmed.len(), trimmed.to_string())
+    };
+
+    let first_segments = segments(first, false);
+    let second_segments = segments(second, false);
+    for (left, right) in first_segments.iter().zip(&second_segments) {
+        let ordering = match (left, right) {
+            (Segment::Number(left), Segment::Number(right)) => value(left).cmp(&value(right)),
+            (Segment::Number(_), Segment::Text(_)) => Ordering::Less,
+            (Segment::Text(_), Segment::Number(_)) => Ordering::Greater,
+            (Segment::Text(left), Segment::Text(right)) => left.cmp(right),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    first_segments.len().cmp(&second_segments.len()).then_with(|| first.cmp(second))
+}
 
-    matrix[len_s1][len_s2]
+/// Shell-style glob match over a whole path: `?` and `*` stay within a segment, `**` spans
+/// segments, and `[a-z]` or `[!0-9]` match one character from a class.
+pub fn glob(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let mut memo = vec![None; (pattern.len() + 1) * (text.len() + 1)];
+    glob_from(&pattern, &text, 0, 0, &mut memo)
+}
+
+/// Whether `pattern[pattern_at..]` matches `text[text_at..]`, memoised on both positions so
+/// runs of wildcards cost polynomial rather than exponential time.
+fn glob_from(pattern: &[char], text: &[char], pattern_at: usize, text_at: usize, memo: &mut [Option<bool>]) -> bool {
+    let slot = pattern_at * (text.len() + 1) + text_at;
+    if let Some(matched) = memo[slot] {
+        return matched;
+    }
+
+    let current = text.get(text_at).copied();
+    let mut step = |pattern_at: usize, text_at: usize| glob_from(pattern, text, pattern_at, text_at, memo);
+
+    let matched = match pattern.get(pattern_at) {
+        None => current.is_none(),
+        Some('*') if pattern.get(pattern_at + 1) == Some(&'*') => {
+            let rest = pattern_at + 2;
+            (pattern.get(rest) == Some(&'/') && step(rest + 1, text_at)) || (text_at..=text.len()).any(|skipped| step(rest, skipped))
+        }
+        Some('*') => {
+            let within = text[text_at..].iter().position(|&c| c == '/').map_or(text.len(), |position| text_at + position);
+            (text_at..=within).any(|skipped| step(pattern_at + 1, skipped))
+        }
+        Some('?') => current.is_some_and(|c| c != '/') && step(pattern_at + 1, text_at + 1),
+        Some('[') => match class(&pattern[pattern_at..]) {
+            Some((members, negated, length)) => {
+                current.is_some_and(|c| c != '/' && in_class(members, negated, c)) && step(pattern_at + length, text_at + 1)
+            }
+            None => current == Some('[') && step(pattern_at + 1, text_at + 1),
+        },
+        Some(&literal) => current == Some(literal) && step(pattern_at + 1, text_at + 1),
+    };
+
+    memo[slot] = Some(matched);
+    matched
+}
+
+/// Parses a `[...]` class at the start of `pattern` into its members, whether it is negated,
+/// and its length in chars.
+fn class(pattern: &[char]) -> Option<(&[char], bool, usize)> {
+    let negated = matches!(pattern.get(1), Some('!' | '^'));
+    let start = if negated { 2 } else { 1 };
+    // A `]` right after the opening bracket is a member rather than the end.
+    let end = start + 1 + pattern.get(start + 1..)?.iter().position(|&c| c == ']')?;
+    Some((&pattern[start..end], negated, end + 1))
+}
+
+fn in_class(members: &[char], negated: bool, c: char) -> bool {
+    let mut index = 0;
+    let mut found = false;
+    while index < members.len() {
+        if index + 2 < members.len() && members[index + 1] == '-' {
+            found |= (members[index]..=members[index + 2]).contains(&c);
+            index += 3;
+        } else {
+            found |= members[index] == c;
+            index += 1;
+        }
+    }
+    found != negated
+}
+
+/// Why a string resembler could not compare a pair
+#[derive(Clone, Debug, PartialEq)]
+pub enum StringError {
+    /// The resembler needs strings of equal length, counted in characters.
+    Length { query: usize, candidate: usize },
+}
+
+impl core::fmt::Display for StringError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StringError::Length { query, candidate } => {
+                write!(f, "lengths differ: query has {} characters, candidate has {}", query, candidate)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StringError {}
+
+/// Where two strings align, as byte ranges into each, with the alignment's score.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span {
+    pub query: Range<usize>,
+    pub candidate: Range<usize>,
+    pub score: f64,
+}
+
+/// Byte offset of every char boundary in `text`, including its end.
+pub(crate) fn boundaries(text: &str) -> Vec<usize> {
+    text.char_indices().map(|(offset, _)| offset).chain(core::iter::once(text.len())).collect()
+}
+
+/// Case-insensitive char equality that keeps indices on the original strings.
+pub(crate) fn same_letter(first: char, second: char) -> bool {
+    first == second || first.to_lowercase().eq(second.to_lowercase())
+}
+
+/// Merges aligned char index pairs into runs that advance together in both strings,
+/// as byte-range spans scored by `score`.
+pub(crate) fn runs(query: &str, candidate: &str, pairs: &[(usize, usize)], score: impl Fn(usize, usize) -> f64) -> Vec<Span> {
+    let query_offsets = boundaries(query);
+    let candidate_offsets = boundaries(candidate);
+    let mut spans: Vec<(usize, usize, usize, f64)> = Vec::new();
+
+    for &(i, j) in pairs {
+        match spans.last_mut() {
+            Some((start_i, start_j, length, total)) if *start_i + *length == i && *start_j + *length == j => {
+                *length += 1;
+                *total += score(i, j);
+            }
+            _ => spans.push((i, j, 1, score(i, j))),
+        }
+    }
+
+    spans.into_iter()
+        .map(|(i, j, length, total)| Span {
+            query: query_offsets[i]..query_offsets[i + length],
+            candidate: candidate_offsets[j]..candidate_offsets[j + length],
+            score: total,
+        })
+        .collect()
+}
+
+/// Score for aligning two characters, as used by global alignment
+pub trait Substitution: Send + Sync {
+    fn score(&self, first: char, second: char) -> f64;
+}
+
+impl<F: Fn(char, char) -> f64 + Send + Sync> Substitution for F {
+    fn score(&self, first: char, second: char) -> f64 {
+        self(first, second)
+    }
+}
+
+/// The same reward for every case-insensitive match and the same cost for every mismatch
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Uniform {
+    pub reward: f64,
+    pub mismatch: f64,
+}
+
+impl Default for Uniform {
+    fn default() -> Self {
+        Self { reward: 1.0, mismatch: 1.0 }
+    }
+}
+
+impl Uniform {
+    pub fn new(reward: f64, mismatch: f64) -> Self {
+        Self { reward, mismatch }
+    }
+}
+
+impl Substitution for Uniform {
+    fn score(&self, first: char, second: char) -> f64 {
+        if same_letter(first, second) { self.reward } else { -self.mismatch }
+    }
 }
 
 pub mod keyboard {
@@ -0,0 +1,126 @@
+use {
+    crate::{
+        assessor::{Resembler, Resemblance},
+        prelude::string::Corpus,
+    },
+};
+
+/// Cosine similarity of TF-IDF vectors weighted by a fitted corpus
+pub struct TfIdf<'corpus> {
+    corpus: &'corpus Corpus,
+}
+
+impl<'corpus> TfIdf<'corpus> {
+    pub fn new(corpus: &'corpus Corpus) -> Self {
+        Self { corpus }
+    }
+
+    fn compute_resemblance(&self, query: &str, candidate: &str) -> f64 {
+        let query_vector = self.corpus.transform(query);
+        let candidate_vector = self.corpus.transform(candidate);
+
+        if query_vector.is_empty() && candidate_vector.is_empty() {
+            return 1.0;
+        }
+
+        let (mut left, mut right) = (0, 0);
+        let mut dot_product = 0.0;
+        while left < query_vector.len() && right < candidate_vector.len() {
+            match query_vector[left].0.cmp(&candidate_vector[right].0) {
+                core::cmp::Ordering::Less => left += 1,
+                core::cmp::Ordering::Greater => right += 1,
+                core::cmp::Ordering::Equal => {
+                    dot_product += query_vector[left].1 * candidate_vector[right].1;
+                    left += 1;
+                    right += 1;
+                }
+            }
+        }
+
+        dot_product
+    }
+}
+
+impl Resembler<String, String, ()> for TfIdf<'_> {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+/// Okapi BM25 relevance of a candidate to a query over a fitted corpus
+pub struct Bm25<'corpus> {
+    corpus: &'corpus Corpus,
+    saturation: f64,
+    normalization: f64,
+}
+
+impl<'corpus> Bm25<'corpus> {
+    pub fn new(corpus: &'corpus Corpus) -> Self {
+        Self { corpus, saturation: 1.2, normalization: 0.75 }
+    }
+
+    /// How quickly repeated terms stop adding to the score (`k1`).
+    pub fn saturation(mut self, saturation: f64) -> Self {
+        self.saturation = saturation.max(0.0);
+        self
+    }
+
+    /// How strongly long candidates are penalized, from 0 to 1 (`b`).
+    pub fn normalization(mut self, normalization: f64) -> Self {
+        self.normalization = normalization.clamp(0.0, 1.0);
+        self
+    }
+
+    fn idf(&self, term: &str) -> f64 {
+        let documents = self.corpus.get_documents() as f64;
+        let frequency = self.corpus.get_frequency(term) as f64;
+        ((documents - frequency + 0.5) / (frequency + 0.5) + 1.0).ln()
+    }
+
+    /// Unbounded BM25 score, suitable for ranking candidates against one query.
+    pub fn score(&self, query: &str, candidate: &str) -> f64 {
+        let query_terms = self.corpus.counts(query);
+        let candidate_terms = self.corpus.counts(candidate);
+
+        let length = candidate_terms.values().sum::<usize>() as f64;
+        let average = self.corpus.average_length();
+        let scale = if average > 0.0 {
+            1.0 - self.normalization + self.normalization * length / average
+        } else {
+            1.0
+        };
+
+        query_terms.keys()
+            .filter_map(|term| candidate_terms.get(term).map(|&count| (term, count as f64)))
+            .map(|(term, count)| {
+                self.idf(term) * count * (self.saturation + 1.0) / (count + self.saturation * scale)
+            })
+            .fold(0.0, |total, score| total + score)
+    }
+
+    /// Score relative to the query matched against itself.
+    fn compute_resemblance(&self, query: &str, candidate: &str) -> f64 {
+        let ideal = self.score(query, query);
+        if ideal <= 0.0 {
+            return if query.is_empty() && candidate.is_empty() { 1.0 } else { 0.0 };
+        }
+
+        self.score(query, candidate) / ideal
+    }
+}
+
+impl Resembler<String, String, ()> for Bm25<'_> {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    assessor::{Resembler, Resemblance},
+    prelude::temporal::utils::{Date, Order, TemporalError},
+};
+
+/// Calendar-aware date matching with day-distance decay
+#[derive(PartialEq)]
+pub struct Calendar {
+    horizon: f64,
+    transposed: f64,
+    slipped: f64,
+    partial: f64,
+    order: Order,
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Self {
+            horizon: 365.0,
+            transposed: 0.8,
+            slipped: 0.7,
+            partial: 0.9,
+            order: Order::default(),
+        }
+    }
+}
+
+impl Calendar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Days apart at which the linear decay reaches disparity.
+    pub fn horizon(mut self, horizon: f64) -> Self {
+        self.horizon = horizon.abs();
+        self
+    }
+
+    /// Score for dates whose day and month are swapped, as in 1984-03-07 and 1984-07-03.
+    pub fn transposed(mut self, transposed: f64) -> Self {
+        self.transposed = transposed;
+        self
+    }
+
+    /// Score for dates sharing day and month a year apart.
+    pub fn slipped(mut self, slipped: f64) -> Self {
+        self.slipped = slipped;
+        self
+    }
+
+    /// Score for a partial date consistent with the other, such as 1984 and 1984-03-07.
+    pub fn partial(mut self, partial: f64) -> Self {
+        self.partial = partial;
+        self
+    }
+
+    /// Day/month order used when parsing ambiguous numeric strings.
+    pub fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    fn decay(&self, days: f64) -> f64 {
+        if self.horizon == 0.0 {
+            return 0.0;
+        }
+        (1.0 - days.abs() / self.horizon).max(0.0)
+    }
+
+    fn compute_resemblance(&self, query: &Date, candidate: &Date) -> f64 {
+        if query == candidate {
+            return 1.0;
+        }
+
+        match (query.month, query.day, candidate.month, candidate.day) {
+            (Some(query_month), Some(query_day), Some(candidate_month), Some(candidate_day)) => {
+                let mut score = self.decay((query.days() - candidate.days()) as f64);
+
+                if query.year == candidate.year && query_month == candidate_day && query_day == candidate_month {
+                    score = score.max(self.transposed);
+                }
+                if (query.year - candidate.year).abs() == 1 && query_month == candidate_month && query_day == candidate_day {
+                    score = score.max(self.slipped);
+                }
+
+                score
+            }
+            (Some(query_month), _, Some(candidate_month), _) => {
+                if query.year == candidate.year && query_month == candidate_month {
+                    return self.partial;
+                }
+                let months = (query.year * 12 + query_month as i32) - (candidate.year * 12 + candidate_month as i32);
+                let mut score = self.decay(months as f64 * 30.44);
+                if months.abs() == 12 {
+                    score = score.max(self.slipped);
+                }
+                self.partial * score
+            }
+            _ => {
+                if query.year == candidate.year {
+                    return self.partial;
+                }
+                let mut score = self.decay((query.year - candidate.year) as f64 * 365.25);
+                if (query.year - candidate.year).abs() == 1 {
+                    score = score.max(self.slipped);
+                }
+                self.partial * score
+            }
+        }
+    }
+}
+
+impl Resembler<Date, Date, TemporalError> for Calendar {
+    fn resemblance(&mut self, query: &Date, candidate: &Date) -> Result<Resemblance, TemporalError> {
+        let score = self.compute_resemblance(query, candidate);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+impl Resembler<String, String, TemporalError> for Calendar {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, TemporalError> {
+        let query = Date::parse(query, self.order)?;
+        let candidate = Date::parse(candidate, self.order)?;
+        self.resemblance(&query, &candidate)
+    }
+}
@@ -0,0 +1,14 @@
+use {
+    core::fmt::{
+        Debug, Formatter
+    },
+    crate::{
+        temporal::*,
+    },
+};
+
+impl Debug for Calendar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "are close on the calendar")
+    }
+}
@@ -0,0 +1,7 @@
+pub mod utils;
+
+pub mod calendar;
+mod format;
+
+pub use utils::*;
+pub use calendar::*;
@@ -0,0 +1,189 @@
+#[derive(Clone, Debug, PartialEq)]
+pub enum TemporalError {
+    /// The text could not be read as a date in any supported format.
+    Unparsable(String),
+    /// The components do not form a real calendar date, such as February 30th.
+    Invalid { year: i32, month: Option<u8>, day: Option<u8> },
+}
+
+impl core::fmt::Display for TemporalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TemporalError::Unparsable(text) => write!(f, "could not parse {:?} as a date", text),
+            TemporalError::Invalid { year, month, day } => {
+                write!(f, "{}-{:?}-{:?} is not a valid date", year, month, day)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TemporalError {}
+
+/// How to read an all-numeric date whose first two fields could be either day or month
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Order {
+    #[default]
+    DayFirst,
+    MonthFirst,
+}
+
+/// A calendar date, possibly known only to the year or month
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Date {
+    pub year: i32,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+}
+
+pub fn is_leap(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "january", "february", "march", "april", "may", "june",
+    "july", "august", "september", "october", "november", "december",
+];
+
+fn month_name(text: &str) -> Option<u8> {
+    let text = text.to_lowercase();
+    if text.len() < 3 {
+        return None;
+    }
+    let text = if text == "sept" { "sep".to_string() } else { text };
+    MONTHS.iter()
+        .position(|month| month.starts_with(&text))
+        .map(|position| position as u8 + 1)
+}
+
+impl Date {
+    pub fn new(year: i32, month: u8, day: u8) -> Result<Self, TemporalError> {
+        Self::partial(year, Some(month), Some(day))
+    }
+
+    pub fn year(year: i32) -> Self {
+        Self { year, month: None, day: None }
+    }
+
+    pub fn month(year: i32, month: u8) -> Result<Self, TemporalError> {
+        Self::partial(year, Some(month), None)
+    }
+
+    pub fn partial(year: i32, month: Option<u8>, day: Option<u8>) -> Result<Self, TemporalError> {
+        let invalid = TemporalError::Invalid { year, month, day };
+        match (month, day) {
+            (None, Some(_)) => return Err(invalid),
+            (Some(month), _) if !(1..=12).contains(&month) => return Err(invalid),
+            (Some(month), Some(day)) if day == 0 || day > days_in_month(year, month) => return Err(invalid),
+            _ => {}
+        }
+        Ok(Self { year, month, day })
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.day.is_some()
+    }
+
+    /// Days since 1970-01-01, counting partial dates from their first day.
+    pub fn days(&self) -> i64 {
+        let month = self.month.unwrap_or(1) as i64;
+        let day = self.day.unwrap_or(1) as i64;
+        let year = self.year as i64 - if month <= 2 { 1 } else { 0 };
+
+        let era = if year >= 0 { year } else { year - 399 } / 400;
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// Reads ISO (`1984-03-07`, `1984-03`, `1984`, `19840307`), numeric (`07/03/1984`,
+    /// `07.03.1984`) and month-name (`7 March 1984`, `March 7, 1984`, `Mar 1984`) dates.
+    pub fn parse(text: &str, order: Order) -> Result<Self, TemporalError> {
+        let unparsable = || TemporalError::Unparsable(text.to_string());
+        let trimmed = text.trim();
+
+        if trimmed.len() == 8 && trimmed.chars().all(|c| c.is_ascii_digit()) {
+            let number = |range: core::ops::Range<usize>| trimmed[range].parse::<u8>().map_err(|_| unparsable());
+            let year = trimmed[0..4].parse::<i32>().map_err(|_| unparsable())?;
+            return Self::new(year, number(4..6)?, number(6..8)?);
+        }
+
+        let mut numbers: Vec<&str> = Vec::new();
+        let mut words: Vec<&str> = Vec::new();
+
+        let mut start = None;
+        for (position, c) in trimmed.char_indices().chain(core::iter::once((trimmed.len(), ' '))) {
+            if c.is_alphanumeric() {
+                start.get_or_insert(position);
+                continue;
+            }
+            if let Some(begin) = start.take() {
+                let token = &trimmed[begin..position];
+                let digits = token.trim_end_matches(|c: char| c.is_alphabetic());
+                if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+                    numbers.push(digits);
+                } else {
+                    words.push(token);
+                }
+            }
+        }
+
+        let parse = |token: &str| token.parse::<i32>().map_err(|_| unparsable());
+        let small = |token: &str| token.parse::<u8>().map_err(|_| unparsable());
+
+        match (words.as_slice(), numbers.as_slice()) {
+            ([], [year]) if year.len() == 4 => Ok(Self::year(parse(year)?)),
+            ([], [first, second]) if first.len() == 4 => Self::month(parse(first)?, small(second)?),
+            ([], [first, second]) if second.len() == 4 => Self::month(parse(second)?, small(first)?),
+            ([], [first, second, third]) if first.len() == 4 => {
+                Self::new(parse(first)?, small(second)?, small(third)?)
+            }
+            ([], [first, second, third]) if third.len() == 4 => {
+                let (day, month) = match order {
+                    Order::DayFirst => (small(first)?, small(second)?),
+                    Order::MonthFirst => (small(second)?, small(first)?),
+                };
+                Self::new(parse(third)?, month, day)
+            }
+            ([word], [year]) if year.len() == 4 => {
+                Self::month(parse(year)?, month_name(word).ok_or_else(unparsable)?)
+            }
+            ([word], [day, year]) | ([word], [year, day]) if year.len() == 4 && day.len() <= 2 => {
+                Self::new(parse(year)?, month_name(word).ok_or_else(unparsable)?, small(day)?)
+            }
+            _ => Err(unparsable()),
+        }
+    }
+}
+
+impl core::str::FromStr for Date {
+    type Err = TemporalError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Date::parse(text, Order::default())
+    }
+}
+
+impl core::fmt::Display for Date {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        Ok(())
+    }
+}
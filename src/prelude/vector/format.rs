@@ -0,0 +1,26 @@
+use {
+    core::fmt::{
+        Debug, Formatter
+    },
+    crate::{
+        vector::*,
+    },
+};
+
+impl Debug for Angular {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "point in the same direction")
+    }
+}
+
+impl Debug for Dot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "are aligned embeddings")
+    }
+}
+
+impl Debug for Euclidean {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "are close in space")
+    }
+}
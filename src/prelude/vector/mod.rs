@@ -0,0 +1,9 @@
+pub mod utils;
+
+pub mod similarity;
+pub mod search;
+mod format;
+
+pub use utils::*;
+pub use similarity::*;
+pub use search::*;
@@ -0,0 +1,281 @@
+use {
+    core::cmp::Ordering,
+    hashish::HashSet,
+    std::collections::BinaryHeap,
+    crate::prelude::vector::utils::{check, cosine, dot, squared_euclidean, VectorError},
+};
+
+/// Distance used to order neighbours; smaller is closer
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Metric {
+    #[default]
+    Cosine,
+    Dot,
+    Euclidean,
+}
+
+impl Metric {
+    pub fn distance(&self, query: &[f32], candidate: &[f32]) -> f32 {
+        match self {
+            Metric::Cosine => 1.0 - cosine(query, candidate),
+            Metric::Dot => 1.0 - dot(query, candidate),
+            Metric::Euclidean => squared_euclidean(query, candidate),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Neighbor {
+    pub id: usize,
+    pub distance: f32,
+}
+
+impl Eq for Neighbor {}
+
+impl PartialOrd for Neighbor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Neighbor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance.total_cmp(&other.distance).then(self.id.cmp(&other.id))
+    }
+}
+
+/// Exact nearest-neighbour search by scanning every vector
+#[derive(Clone, Debug, PartialEq)]
+pub struct Flat {
+    metric: Metric,
+    vectors: Vec<Vec<f32>>,
+}
+
+impl Flat {
+    pub fn new(metric: Metric) -> Self {
+        Self { metric, vectors: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.vectors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vectors.is_empty()
+    }
+
+    pub fn get(&self, id: usize) -> Option<&[f32]> {
+        self.vectors.get(id).map(Vec::as_slice)
+    }
+
+    pub fn insert(&mut self, vector: Vec<f32>) -> Result<usize, VectorError> {
+        match self.vectors.first() {
+            Some(first) => check(first, &vector)?,
+            None if vector.is_empty() => return Err(VectorError::Empty),
+            None => {}
+        }
+        self.vectors.push(vector);
+        Ok(self.vectors.len() - 1)
+    }
+
+    pub fn search(&self, query: &[f32], count: usize) -> Result<Vec<Neighbor>, VectorError> {
+        if let Some(first) = self.vectors.first() {
+            check(query, first)?;
+        }
+
+        let mut heap = BinaryHeap::with_capacity(count + 1);
+        for (id, vector) in self.vectors.iter().enumerate() {
+            heap.push(Neighbor { id, distance: self.metric.distance(query, vector) });
+            if heap.len() > count {
+                heap.pop();
+            }
+        }
+
+        Ok(heap.into_sorted_vec())
+    }
+}
+
+/// Approximate nearest-neighbour search over a hierarchical navigable small-world graph
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hnsw {
+    metric: Metric,
+    connections: usize,
+    construction: usize,
+    exploration: usize,
+    vectors: Vec<Vec<f32>>,
+    links: Vec<Vec<Vec<usize>>>,
+    entry: Option<usize>,
+    seed: u64,
+}
+
+impl Hnsw {
+    pub fn new(metric: Metric) -> Self {
+        Self {
+            metric,
+            connections: 16,
+            construction: 100,
+            exploration: 50,
+            vectors: Vec::new(),
+            links: Vec::new(),
+            entry: None,
+            seed: 0x2545f4914f6cdd1d,
+        }
+    }
+
+    /// Links kept per node on upper layers; layer zero keeps twice as many.
+    pub fn connections(mut self, connections: usize) -> Self {
+        self.connections = connections.max(2);
+        self
+    }
+
+    /// Candidate list size while inserting.
+    pub fn construction(mut self, construction: usize) -> Self {
+        self.construction = construction.max(1);
+        self
+    }
+
+    /// Candidate list size while searching.
+    pub fn exploration(mut self, exploration: usize) -> Self {
+        self.exploration = exploration.max(1);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.vectors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vectors.is_empty()
+    }
+
+    pub fn get(&self, id: usize) -> Option<&[f32]> {
+        self.vectors.get(id).map(Vec::as_slice)
+    }
+
+    fn level(&mut self) -> usize {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+
+        let uniform = ((self.seed >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+        let scale = 1.0 / (self.connections as f64).ln();
+        (-uniform.ln() * scale) as usize
+    }
+
+    fn capacity(&self, layer: usize) -> usize {
+        if layer == 0 { self.connections * 2 } else { self.connections }
+    }
+
+    fn distance(&self, query: &[f32], id: usize) -> f32 {
+        self.metric.distance(query, &self.vectors[id])
+    }
+
+    /// Best-first search of one layer, returning up to `width` closest nodes in ascending distance.
+    fn layer(&self, query: &[f32], entries: &[usize], width: usize, layer: usize) -> Vec<Neighbor> {
+        let mut visited: HashSet<usize> = entries.iter().copied().collect();
+        let mut frontier: BinaryHeap<core::cmp::Reverse<Neighbor>> = BinaryHeap::new();
+        let mut found: BinaryHeap<Neighbor> = BinaryHeap::new();
+
+        for &id in entries {
+            let neighbor = Neighbor { id, distance: self.distance(query, id) };
+            frontier.push(core::cmp::Reverse(neighbor));
+            found.push(neighbor);
+        }
+
+        while let Some(core::cmp::Reverse(current)) = frontier.pop() {
+            if found.len() >= width && found.peek().is_some_and(|worst| current.distance > worst.distance) {
+                break;
+            }
+
+            for &id in &self.links[current.id][layer] {
+                if !visited.insert(id) {
+                    continue;
+                }
+
+                let neighbor = Neighbor { id, distance: self.distance(query, id) };
+                if found.len() < width || found.peek().is_some_and(|worst| neighbor.distance < worst.distance) {
+                    frontier.push(core::cmp::Reverse(neighbor));
+                    found.push(neighbor);
+                    if found.len() > width {
+                        found.pop();
+                    }
+                }
+            }
+        }
+
+        found.into_sorted_vec()
+    }
+
+    pub fn insert(&mut self, vector: Vec<f32>) -> Result<usize, VectorError> {
+        match self.vectors.first() {
+            Some(first) => check(first, &vector)?,
+            None if vector.is_empty() => return Err(VectorError::Empty),
+            None => {}
+        }
+
+        let id = self.vectors.len();
+        let level = self.level();
+        self.vectors.push(vector);
+        self.links.push(vec![Vec::new(); level + 1]);
+
+        let entry = match self.entry {
+            Some(entry) => entry,
+            None => {
+                self.entry = Some(id);
+                return Ok(id);
+            }
+        };
+
+        let top = self.links[entry].len() - 1;
+        let query = self.vectors[id].clone();
+        let mut closest = vec![entry];
+
+        for layer in (level + 1..=top).rev() {
+            closest = vec![self.layer(&query, &closest, 1, layer)[0].id];
+        }
+
+        for layer in (0..=level.min(top)).rev() {
+            let candidates = self.layer(&query, &closest, self.construction, layer);
+            let capacity = self.capacity(layer);
+            let chosen: Vec<usize> = candidates.iter().take(capacity).map(|neighbor| neighbor.id).collect();
+
+            for &neighbor in &chosen {
+                self.links[neighbor][layer].push(id);
+                if self.links[neighbor][layer].len() > capacity {
+                    let origin = self.vectors[neighbor].clone();
+                    let mut ranked: Vec<Neighbor> = self.links[neighbor][layer].iter()
+                        .map(|&linked| Neighbor { id: linked, distance: self.distance(&origin, linked) })
+                        .collect();
+                    ranked.sort();
+                    self.links[neighbor][layer] = ranked.into_iter().take(capacity).map(|ranked| ranked.id).collect();
+                }
+            }
+
+            self.links[id][layer] = chosen;
+            closest = candidates.into_iter().map(|neighbor| neighbor.id).collect();
+        }
+
+        if level > top {
+            self.entry = Some(id);
+        }
+
+        Ok(id)
+    }
+
+    pub fn search(&self, query: &[f32], count: usize) -> Result<Vec<Neighbor>, VectorError> {
+        let entry = match self.entry {
+            Some(entry) => entry,
+            None => return Ok(Vec::new()),
+        };
+        check(query, &self.vectors[entry])?;
+
+        let mut closest = vec![entry];
+        for layer in (1..self.links[entry].len()).rev() {
+            closest = vec![self.layer(query, &closest, 1, layer)[0].id];
+        }
+
+        let mut neighbors = self.layer(query, &closest, self.exploration.max(count), 0);
+        neighbors.truncate(count);
+        Ok(neighbors)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    assessor::{Resembler, Resemblance},
+    prelude::vector::utils::{check, cosine, dot, squared_euclidean, VectorError},
+};
+
+/// Cosine similarity, with opposite directions clamped to disparity
+#[derive(PartialEq)]
+pub struct Angular;
+
+impl Resembler<[f32], [f32], VectorError> for Angular {
+    fn resemblance(&mut self, query: &[f32], candidate: &[f32]) -> Result<Resemblance, VectorError> {
+        check(query, candidate)?;
+        Ok((cosine(query, candidate) as f64).clamp(0.0, 1.0).into())
+    }
+}
+
+/// Dot product of unit-length embeddings, mapped from -1..=1 onto 0..=1
+#[derive(PartialEq)]
+pub struct Dot;
+
+impl Resembler<[f32], [f32], VectorError> for Dot {
+    fn resemblance(&mut self, query: &[f32], candidate: &[f32]) -> Result<Resemblance, VectorError> {
+        check(query, candidate)?;
+        let score = (dot(query, candidate) as f64 + 1.0) / 2.0;
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
+
+/// Euclidean distance turned into similarity as `1 / (1 + d / scale)`
+#[derive(PartialEq)]
+pub struct Euclidean {
+    scale: f64,
+}
+
+impl Default for Euclidean {
+    fn default() -> Self {
+        Self { scale: 1.0 }
+    }
+}
+
+impl Euclidean {
+    pub fn new(scale: f64) -> Self {
+        Self { scale: scale.abs().max(f64::MIN_POSITIVE) }
+    }
+}
+
+impl Resembler<[f32], [f32], VectorError> for Euclidean {
+    fn resemblance(&mut self, query: &[f32], candidate: &[f32]) -> Result<Resemblance, VectorError> {
+        check(query, candidate)?;
+        let distance = (squared_euclidean(query, candidate) as f64).sqrt();
+        Ok((1.0 / (1.0 + distance / self.scale)).into())
+    }
+}
+
+macro_rules! owned {
+    ($($resembler:ty),*) => {
+        $(
+            impl Resembler<Vec<f32>, Vec<f32>, VectorError> for $resembler {
+                fn resemblance(&mut self, query: &Vec<f32>, candidate: &Vec<f32>) -> Result<Resemblance, VectorError> {
+                    Resembler::<[f32], [f32], VectorError>::resemblance(self, query.as_slice(), candidate.as_slice())
+                }
+            }
+        )*
+    };
+}
+
+owned!(Angular, Dot, Euclidean);
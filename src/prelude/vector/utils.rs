@@ -0,0 +1,45 @@
+#[derive(Clone, Debug, PartialEq)]
+pub enum VectorError {
+    Dimension { query: usize, candidate: usize },
+    Empty,
+}
+
+impl core::fmt::Display for VectorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VectorError::Dimension { query, candidate } => {
+                write!(f, "vector dimensions differ: {} against {}", query, candidate)
+            }
+            VectorError::Empty => write!(f, "vector is empty"),
+        }
+    }
+}
+
+impl std::error::Error for VectorError {}
+
+pub fn check(query: &[f32], candidate: &[f32]) -> Result<(), VectorError> {
+    if query.len() != candidate.len() {
+        return Err(VectorError::Dimension { query: query.len(), candidate: candidate.len() });
+    }
+    if query.is_empty() {
+        return Err(VectorError::Empty);
+    }
+    Ok(())
+}
+
+pub fn dot(query: &[f32], candidate: &[f32]) -> f32 {
+    query.iter().zip(candidate).map(|(a, b)| a * b).sum()
+}
+
+pub fn norm(vector: &[f32]) -> f32 {
+    dot(vector, vector).sqrt()
+}
+
+pub fn cosine(query: &[f32], candidate: &[f32]) -> f32 {
+    let norms = norm(query) * norm(candidate);
+    if norms > 0.0 { dot(query, candidate) / norms } else { 0.0 }
+}
+
+pub fn squared_euclidean(query: &[f32], candidate: &[f32]) -> f32 {
+    query.iter().zip(candidate).map(|(a, b)| (a - b) * (a - b)).sum()
+}
@@ -0,0 +1,78 @@
+use crate::{
+    assessor::{Resembler, Resemblance},
+    prelude::web::utils::{host, ip, registrable, suffix},
+};
+
+/// Domain names compared through their registrable domain: a subdomain of the other scores
+/// `subdomain`, a sibling under the same registrable domain `sibling`, and the same name under
+/// another suffix, as `example.com` and `example.co.uk`, `suffix`; IP addresses only match themselves
+#[derive(PartialEq)]
+pub struct Domain {
+    subdomain: f64,
+    sibling: f64,
+    suffix: f64,
+}
+
+impl Default for Domain {
+    fn default() -> Self {
+        Self { subdomain: 0.9, sibling: 0.75, suffix: 0.5 }
+    }
+}
+
+impl Domain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Score for one host lying under the other, as `mail.example.com` under `example.com`.
+    pub fn subdomain(mut self, subdomain: f64) -> Self {
+        self.subdomain = subdomain;
+        self
+    }
+
+    /// Score for distinct hosts sharing a registrable domain, as `a.example.com` and `b.example.com`.
+    pub fn sibling(mut self, sibling: f64) -> Self {
+        self.sibling = sibling;
+        self
+    }
+
+    /// Score for the same registered name under different public suffixes.
+    pub fn suffix(mut self, suffix: f64) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    pub(crate) fn compute_resemblance(&self, query: &str, candidate: &str) -> f64 {
+        let query = host(query);
+        let candidate = host(candidate);
+        if query == candidate {
+            return 1.0;
+        }
+        if ip(&query) || ip(&candidate) {
+            return 0.0;
+        }
+
+        let under = |child: &str, parent: &str| child.ends_with(&format!(".{}", parent));
+        let query_registrable = registrable(&query);
+        let candidate_registrable = registrable(&candidate);
+
+        if query_registrable == candidate_registrable {
+            if under(&query, &candidate) || under(&candidate, &query) { self.subdomain } else { self.sibling }
+        } else {
+            let name = |registered: &str| registered.split('.').next().unwrap_or("").to_string();
+            let distinct = suffix(&query) != suffix(&candidate);
+            if distinct && name(query_registrable) == name(candidate_registrable) { self.suffix } else { 0.0 }
+        }
+    }
+}
+
+impl Resembler<String, String, ()> for Domain {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
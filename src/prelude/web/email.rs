@@ -0,0 +1,153 @@
+use crate::{
+    assessor::{Resembler, Resemblance},
+    prelude::{
+        string::Jaro,
+        web::{domain::Domain, utils::host},
+    },
+};
+
+/// How a mail provider reads the local part of its addresses
+#[derive(Clone, Debug, PartialEq)]
+pub struct Provider {
+    domains: Vec<String>,
+    dots: bool,
+    separator: Option<char>,
+}
+
+impl Provider {
+    /// A provider serving the given domains, the first being canonical; by default dots count
+    /// and a `+` starts a tag.
+    pub fn new(domains: &[&str]) -> Self {
+        Self {
+            domains: domains.iter().map(|domain| domain.to_lowercase()).collect(),
+            dots: false,
+            separator: Some('+'),
+        }
+    }
+
+    /// Whether dots in the local part are ignored, as Gmail does.
+    pub fn dots(mut self, dots: bool) -> Self {
+        self.dots = dots;
+        self
+    }
+
+    /// Character starting a tag that is stripped from the local part, if any.
+    pub fn separator(mut self, separator: Option<char>) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    pub fn get_domains(&self) -> &[String] {
+        &self.domains
+    }
+}
+
+/// Rules of the large mail providers, for `Email::default()`.
+pub fn providers() -> Vec<Provider> {
+    vec![
+        Provider::new(&["gmail.com", "googlemail.com"]).dots(true),
+        Provider::new(&["outlook.com", "hotmail.com", "live.com", "msn.com"]),
+        Provider::new(&["yahoo.com", "ymail.com", "rocketmail.com"]).separator(Some('-')),
+        Provider::new(&["icloud.com", "me.com", "mac.com"]),
+        Provider::new(&["proton.me", "protonmail.com", "pm.me"]),
+        Provider::new(&["fastmail.com", "fastmail.fm"]),
+    ]
+}
+
+/// Email addresses compared after canonicalisation: lowercased, tags stripped, and provider
+/// rules applied such as Gmail ignoring dots; distinct addresses fall back to the local part's
+/// similarity and the domains' relation
+#[derive(PartialEq)]
+pub struct Email {
+    providers: Vec<Provider>,
+    separator: Option<char>,
+    domain: f64,
+}
+
+impl Default for Email {
+    fn default() -> Self {
+        Self { providers: providers(), separator: Some('+'), domain: 0.3 }
+    }
+}
+
+impl Email {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a provider's rules, taking precedence over the bundled ones for its domains.
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.providers.insert(0, provider);
+        self
+    }
+
+    /// Tag separator for domains no provider covers, `+` by default.
+    pub fn separator(mut self, separator: Option<char>) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Share of the score the domain carries when canonical addresses differ.
+    pub fn domain(mut self, domain: f64) -> Self {
+        self.domain = domain.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The canonical form of an address, `local@domain`; text without an `@` is only trimmed
+    /// and lowercased.
+    pub fn canonical(&self, email: &str) -> String {
+        let email = email.trim();
+        let Some((local, domain)) = email.rsplit_once('@') else {
+            return email.to_lowercase();
+        };
+
+        let domain = host(domain);
+        let provider = self.providers.iter().find(|provider| provider.domains.contains(&domain));
+        let separator = provider.map_or(self.separator, |provider| provider.separator);
+
+        let mut local = local.to_lowercase();
+        if let Some(position) = separator.and_then(|separator| local.find(separator)).filter(|&position| position > 0) {
+            local.truncate(position);
+        }
+        if provider.is_some_and(|provider| provider.dots) {
+            local.retain(|c| c != '.');
+        }
+
+        let domain = provider.map_or(domain, |provider| provider.domains[0].clone());
+        format!("{}@{}", local, domain)
+    }
+
+    fn compute_resemblance(&self, query: &str, candidate: &str) -> f64 {
+        let query = self.canonical(query);
+        let candidate = self.canonical(candidate);
+        if query == candidate {
+            return 1.0;
+        }
+
+        let split = |email: &str| email.rsplit_once('@').map_or((email.to_string(), None), |(local, domain)| (local.to_string(), Some(domain.to_string())));
+        let (query_local, query_domain) = split(&query);
+        let (candidate_local, candidate_domain) = split(&candidate);
+
+        let local = Jaro::default()
+            .resemblance(&query_local, &candidate_local)
+            .map_or(0.0, |resemblance| resemblance.to_f64());
+        // Text without an `@` has no domain to agree with, so that share scores nothing.
+        let domain = match (query_domain, candidate_domain) {
+            (Some(query_domain), Some(candidate_domain)) => Domain::default().compute_resemblance(&query_domain, &candidate_domain),
+            _ => 0.0,
+        };
+
+        local * (1.0 - self.domain) + domain * self.domain
+    }
+}
+
+impl Resembler<String, String, ()> for Email {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
@@ -0,0 +1,26 @@
+use {
+    core::fmt::{
+        Debug, Formatter
+    },
+    crate::{
+        web::*,
+    },
+};
+
+impl Debug for Domain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "belong to the same domain")
+    }
+}
+
+impl Debug for Email {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "reach the same mailbox")
+    }
+}
+
+impl Debug for Url {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "point to the same page")
+    }
+}
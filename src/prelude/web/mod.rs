@@ -0,0 +1,11 @@
+pub mod utils;
+
+pub mod domain;
+pub mod email;
+pub mod url;
+mod format;
+
+pub use utils::*;
+pub use domain::*;
+pub use email::*;
+pub use url::*;
\ No newline at end of file
@@ -0,0 +1,104 @@
+use crate::{
+    assessor::{Resembler, Resemblance},
+    prelude::{
+        string::Jaro,
+        web::{domain::Domain, utils::Link},
+    },
+};
+
+/// URLs compared after canonicalisation, ignoring `http` against `https`, `www.`, default
+/// ports, trailing slashes, percent-encoding, query parameter order and fragments; distinct
+/// URLs on related hosts and the same port fall back to the similarity of their paths and queries
+#[derive(Default, PartialEq)]
+pub struct Url {
+    scheme: bool,
+    fragment: bool,
+}
+
+impl Url {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the scheme must agree, so `http` and `https` differ.
+    pub fn scheme(mut self, scheme: bool) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Whether the fragment must agree, as for single-page applications routing on it.
+    pub fn fragment(mut self, fragment: bool) -> Self {
+        self.fragment = fragment;
+        self
+    }
+
+    /// The canonical form of a URL, with query parameters sorted.
+    pub fn canonical(&self, url: &str) -> String {
+        self.render(&Link::parse(url))
+    }
+
+    fn render(&self, link: &Link) -> String {
+        let mut canonical = String::new();
+        if self.scheme && !link.scheme.is_empty() {
+            canonical.push_str(&link.scheme);
+            canonical.push_str("://");
+        }
+
+        canonical.push_str(&link.host);
+        if let Some(port) = link.port {
+            canonical.push_str(&format!(":{}", port));
+        }
+        canonical.push_str(&link.path);
+
+        if !link.query.is_empty() {
+            let pairs: Vec<String> = link.query.iter().map(|(key, value)| format!("{}={}", key, value)).collect();
+            canonical.push('?');
+            canonical.push_str(&pairs.join("&"));
+        }
+        if self.fragment && !link.fragment.is_empty() {
+            canonical.push('#');
+            canonical.push_str(&link.fragment);
+        }
+
+        canonical
+    }
+
+    fn compute_resemblance(&self, query: &str, candidate: &str) -> f64 {
+        let query = Link::parse(query);
+        let candidate = Link::parse(candidate);
+        if self.render(&query) == self.render(&candidate) {
+            return 1.0;
+        }
+
+        let hosts = Domain::default().compute_resemblance(&query.host, &candidate.host);
+        if hosts == 0.0 || query.port != candidate.port || (self.scheme && query.scheme != candidate.scheme) {
+            return 0.0;
+        }
+
+        let jaro = |query: &String, candidate: &String| {
+            Jaro::default().resemblance(query, candidate).map_or(0.0, |resemblance| resemblance.to_f64())
+        };
+        let paths = jaro(&query.path, &candidate.path);
+        let shared = query.query.iter().filter(|pair| candidate.query.contains(pair)).count();
+        let total = query.query.len() + candidate.query.len() - shared;
+        let parameters = if total == 0 { 1.0 } else { shared as f64 / total as f64 };
+
+        let score = hosts * 0.4 + paths * 0.4 + parameters * 0.2;
+        if self.fragment {
+            score * 0.9 + jaro(&query.fragment, &candidate.fragment) * 0.1
+        } else {
+            score
+        }
+    }
+}
+
+impl Resembler<String, String, ()> for Url {
+    fn resemblance(&mut self, query: &String, candidate: &String) -> Result<Resemblance, ()> {
+        if query == candidate {
+            return Ok(Resemblance::Perfect);
+        }
+
+        let score = self.compute_resemblance(query, candidate);
+        Ok(score.clamp(0.0, 1.0).into())
+    }
+}
@@ -0,0 +1,146 @@
+/// Public suffixes of more than one label, from the most used entries of the Public Suffix List;
+/// any other domain is taken to end in a single-label suffix such as `com`.
+pub const SUFFIXES: &[&str] = &[
+    "co.uk", "org.uk", "ac.uk", "gov.uk", "me.uk", "ltd.uk", "plc.uk", "net.uk", "sch.uk", "nhs.uk",
+    "com.au", "net.au", "org.au", "edu.au", "gov.au", "co.nz", "org.nz", "net.nz", "govt.nz",
+    "co.jp", "ne.jp", "or.jp", "ac.jp", "go.jp", "co.kr", "or.kr", "com.cn", "net.cn", "org.cn",
+    "com.hk", "com.tw", "com.sg", "com.my", "co.in", "net.in", "org.in", "co.id", "co.th", "com.ph",
+    "com.br", "net.br", "org.br", "com.mx", "com.ar", "com.co", "com.pe", "co.za", "org.za",
+    "com.tr", "co.il", "com.sa", "com.eg", "com.ng", "co.ke", "com.ua", "com.pl", "co.at", "or.at",
+    "github.io", "gitlab.io", "herokuapp.com", "appspot.com", "blogspot.com", "netlify.app",
+    "vercel.app", "pages.dev", "workers.dev", "azurewebsites.net", "cloudfront.net", "s3.amazonaws.com",
+];
+
+/// Host name lowercased, without a trailing dot or a leading `www.`.
+pub fn host(text: &str) -> String {
+    let lowered = text.trim().trim_end_matches('.').to_lowercase();
+    match lowered.strip_prefix("www.") {
+        Some(rest) if rest.contains('.') => rest.to_string(),
+        _ => lowered,
+    }
+}
+
+/// Whether a host is an IP literal: a dotted IPv4 address or a bracketed IPv6 one.
+pub fn ip(host: &str) -> bool {
+    if let Some(inner) = host.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+        return inner.parse::<std::net::Ipv6Addr>().is_ok();
+    }
+    host.parse::<std::net::Ipv4Addr>().is_ok()
+}
+
+/// The public suffix a host ends in, such as `co.uk` for `news.bbc.co.uk`.
+pub fn suffix(host: &str) -> &str {
+    SUFFIXES.iter()
+        .filter(|suffix| host == **suffix || host.ends_with(&format!(".{}", suffix)))
+        .max_by_key(|suffix| suffix.len())
+        .map_or_else(|| host.rsplit('.').next().unwrap_or(host), |suffix| &host[host.len() - suffix.len()..])
+}
+
+/// The registrable domain, one label under the public suffix: `news.bbc.co.uk` gives `bbc.co.uk`.
+/// An IP literal is its own registrable domain.
+pub fn registrable(host: &str) -> &str {
+    if ip(host) {
+        return host;
+    }
+
+    let suffix = suffix(host);
+    if host.len() <= suffix.len() {
+        return host;
+    }
+
+    let rest = &host[..host.len() - suffix.len() - 1];
+    let start = rest.rfind('.').map_or(0, |position| position + 1);
+    &host[start..]
+}
+
+/// Decodes `%XX` escapes, and with `plus` a `+` as a space, as in form-encoded queries;
+/// malformed escapes are kept as written and invalid UTF-8 is replaced.
+pub fn percent_decode(text: &str, plus: bool) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let escape = match bytes.get(index..index + 3) {
+            Some([b'%', high, low]) if high.is_ascii_hexdigit() && low.is_ascii_hexdigit() => {
+                u8::from_str_radix(&text[index + 1..index + 3], 16).ok()
+            }
+            _ => None,
+        };
+
+        match (bytes[index], escape) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            (b'+', _) if plus => {
+                decoded.push(b' ');
+                index += 1;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// A URL split into its parts, lowercased where case carries no meaning
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Link {
+    pub scheme: String,
+    pub host: String,
+    pub port: Option<u16>,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub fragment: String,
+}
+
+impl Link {
+    /// Parses `scheme://user@host:port/path?query#fragment`, accepting a bare `host/path` too.
+    ///
+    /// The host loses any `www.`, default ports are dropped, the path is percent-decoded without
+    /// a trailing slash, and query parameters are decoded and sorted.
+    pub fn parse(text: &str) -> Self {
+        let text = text.trim();
+        let (scheme, rest) = match text.split_once("://") {
+            Some((scheme, rest)) => (scheme.to_lowercase(), rest),
+            None => (String::new(), text),
+        };
+
+        let (rest, fragment) = rest.split_once('#').unwrap_or((rest, ""));
+        let (rest, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let (authority, path) = rest.find('/').map_or((rest, ""), |position| (&rest[..position], &rest[position..]));
+        let authority = authority.rsplit('@').next().unwrap_or(authority);
+
+        let (name, port) = match authority.rsplit_once(':') {
+            Some((name, port)) if !port.is_empty() && port.chars().all(|c| c.is_ascii_digit()) => (name, port.parse().ok()),
+            _ => (authority, None),
+        };
+        let port = match (scheme.as_str(), port) {
+            ("http", Some(80)) | ("https", Some(443)) | ("ftp", Some(21)) => None,
+            (_, port) => port,
+        };
+
+        let path = percent_decode(path, false);
+        let mut query: Vec<(String, String)> = query.split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (percent_decode(key, true), percent_decode(value, true))
+            })
+            .collect();
+        query.sort();
+
+        Self {
+            scheme,
+            host: host(name),
+            port,
+            path: path.trim_end_matches('/').to_string(),
+            query,
+            fragment: percent_decode(fragment, false),
+        }
+    }
+}
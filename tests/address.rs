@@ -0,0 +1,38 @@
+use matchete::address::Address;
+
+fn address(fields: [&str; 7]) -> Address {
+    let [number, street, unit, city, region, postal, country] = fields.map(String::from);
+    Address { number, street, unit, city, region, postal, country }
+}
+
+#[test]
+fn unit_on_its_own_line() {
+    assert_eq!(
+        Address::parse("123 Main St, Suite 200, Springfield, IL 62704"),
+        address(["123", "Main St", "200", "Springfield", "IL", "62704", ""]),
+    );
+    assert_eq!(
+        Address::parse("123 Main St\nApt 4B\nSpringfield, IL 62704\nUSA"),
+        address(["123", "Main St", "4B", "Springfield", "IL", "62704", "USA"]),
+    );
+    assert_eq!(
+        Address::parse("Flat 3, 10 Downing Street, London SW1A 2AA, United Kingdom"),
+        address(["10", "Downing Street", "3", "London", "", "SW1A 2AA", "United Kingdom"]),
+    );
+}
+
+#[test]
+fn common_layouts() {
+    assert_eq!(
+        Address::parse("123 Main St Apt 4B, Springfield, IL 62704, USA"),
+        address(["123", "Main St", "4B", "Springfield", "IL", "62704", "USA"]),
+    );
+    assert_eq!(
+        Address::parse("Unter den Linden 77, 10117 Berlin, Germany"),
+        address(["77", "Unter den Linden", "", "Berlin", "", "10117", "Germany"]),
+    );
+    assert_eq!(
+        Address::parse("500 Oak Ave #12, Portland, OR 97205"),
+        address(["500", "Oak Ave", "12", "Portland", "OR", "97205", ""]),
+    );
+}
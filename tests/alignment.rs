@@ -0,0 +1,53 @@
+use matchete::string::utils::{alignment, edit_distance, Step};
+
+#[test]
+fn render() {
+    assert_eq!(alignment("kitten", "sitting").render(), "[k→s]itt[e→i]n[+g]");
+    assert_eq!(alignment("recieve", "receive").render(), "rec[ie⇄ei]ve");
+    assert_eq!(alignment("abcdef", "abef").render(), "ab[-cd]ef");
+    assert_eq!(alignment("flaw", "lawn").render(), "[-f]law[+n]");
+    assert_eq!(alignment("same", "same").render(), "same");
+    assert_eq!(alignment("", "").render(), "");
+    assert_eq!(alignment("", "new").render(), "[+new]");
+    assert_eq!(alignment("xy", "ab").to_string(), "[xy→ab]");
+}
+
+#[test]
+fn adjacent_transpositions_render_apart() {
+    let alignment = alignment("abcd", "badc");
+
+    assert_eq!(alignment.render(), "[ab⇄ba][cd⇄dc]");
+    assert_eq!(alignment.transpositions().collect::<Vec<_>>(), [('a', 'b'), ('c', 'd')]);
+    assert_eq!(alignment.distance, 2);
+}
+
+#[test]
+fn steps_agree_with_distance() {
+    let cases = [("kitten", "sitting"), ("ca", "abc"), ("naïve", "naive"), ("日本語", "日本"), ("teh", "the"), ("", "abc")];
+
+    for (first, second) in cases {
+        let alignment = alignment(first, second);
+        let cost = alignment.steps.iter().filter(|step| !matches!(step, Step::Match(_))).count();
+
+        assert_eq!(alignment.distance, edit_distance(first, second), "{} {}", first, second);
+        assert_eq!(cost, alignment.distance, "{} {}", first, second);
+
+        let rebuilt: String = alignment.steps.iter()
+            .flat_map(|step| match *step {
+                Step::Match(c) | Step::Substitute(_, c) | Step::Insert(c) => vec![c],
+                Step::Transpose(first, second) => vec![second, first],
+                Step::Delete(_) => vec![],
+            })
+            .collect();
+        assert_eq!(rebuilt, second);
+    }
+}
+
+#[test]
+fn substitutions() {
+    let alignment = alignment("grey", "gray");
+
+    assert_eq!(alignment.steps, [Step::Match('g'), Step::Match('r'), Step::Substitute('e', 'a'), Step::Match('y')]);
+    assert_eq!(alignment.substitutions().collect::<Vec<_>>(), [('e', 'a')]);
+    assert_eq!(alignment.transpositions().count(), 0);
+}
@@ -0,0 +1,67 @@
+use matchete::{
+    language::{Lemmatizer, Porter2},
+    string::{Corpus, Cosine, Terms, Words},
+    IndexError, Resemblance, Resembler,
+};
+
+const DOCUMENTS: [&str; 3] = ["running shoes", "trail running", "shoe store"];
+
+fn stemmed() -> Words {
+    Words::default().stemmer(Porter2)
+}
+
+#[test]
+fn round_trip_with_settings() {
+    let corpus = Corpus::new(Terms::Words).words(stemmed()).fit(DOCUMENTS);
+    let bytes = corpus.encode().unwrap();
+
+    let decoded = Corpus::decode_with(&bytes, stemmed()).unwrap();
+    assert!(decoded == corpus);
+    assert_eq!(decoded.get_frequency("run"), 2);
+    assert_eq!(decoded.transform("running"), corpus.transform("running"));
+}
+
+#[test]
+fn mismatched_settings_are_rejected() {
+    let bytes = Corpus::new(Terms::Words).words(stemmed()).fit(DOCUMENTS).encode().unwrap();
+
+    assert!(matches!(Corpus::decode(&bytes), Err(IndexError::Settings { .. })));
+    assert!(matches!(Corpus::decode_with(&bytes, stemmed().min_word_len(3)), Err(IndexError::Settings { .. })));
+    assert!(matches!(Corpus::decode_with(&bytes, stemmed().stop_words(["store"])), Err(IndexError::Settings { .. })));
+}
+
+#[test]
+fn lemmatizers_differing_on_rare_words_are_rejected() {
+    let lemmas = || Lemmatizer::new().lemma("shoes", "shoe");
+    let bytes = Corpus::new(Terms::Words).words(Words::default().stemmer(lemmas())).fit(DOCUMENTS).encode().unwrap();
+
+    assert!(Corpus::decode_with(&bytes, Words::default().stemmer(lemmas())).is_ok());
+    let widened = Words::default().stemmer(lemmas().lemma("geese", "goose"));
+    assert!(matches!(Corpus::decode_with(&bytes, widened), Err(IndexError::Settings { .. })));
+}
+
+#[test]
+fn ngram_corpora_ignore_word_settings() {
+    let bytes = Corpus::new(Terms::Ngrams(3)).words(stemmed()).fit(DOCUMENTS).encode().unwrap();
+    let decoded = Corpus::decode(&bytes).unwrap();
+
+    assert!(decoded == Corpus::new(Terms::Ngrams(3)).fit(DOCUMENTS));
+}
+
+#[test]
+fn multibyte_text_shorter_than_a_gram() {
+    let corpus = Corpus::new(Terms::Ngrams(3)).fit(["日本", "東京都", "café", "é"]);
+
+    assert_eq!(corpus.get_frequency("日本"), 1);
+    assert_eq!(corpus.get_frequency("東京都"), 1);
+    assert_eq!(corpus.get_frequency("afé"), 1);
+    assert!(!corpus.transform("日本").is_empty());
+}
+
+#[test]
+fn cosine_on_short_multibyte_text() {
+    let mut cosine = Cosine::new(3);
+
+    assert_eq!(cosine.resemblance(&"日本".to_string(), &"日本".to_string()).unwrap(), Resemblance::Perfect);
+    assert_eq!(cosine.resemblance(&"日本".to_string(), &"日本語".to_string()).unwrap(), Resemblance::Disparity);
+}
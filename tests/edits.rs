@@ -0,0 +1,180 @@
+use matchete::{histogram, myers, patience, Edit, Operation};
+
+type Diff = fn(&[char], &[char]) -> Vec<Edit>;
+
+const ALGORITHMS: [(&str, Diff); 3] = [
+    ("myers", myers::<char>),
+    ("patience", patience::<char>),
+    ("histogram", histogram::<char>),
+];
+
+fn chars(text: &str) -> Vec<char> {
+    text.chars().collect()
+}
+
+/// Deterministic strings over a small alphabet, so repeats and partial matches are common.
+fn samples(count: usize) -> Vec<(Vec<char>, Vec<char>)> {
+    let mut state: u64 = 0x9e3779b97f4a7c15;
+    let mut next = move |bound: u64| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) % bound
+    };
+    let text = |next: &mut dyn FnMut(u64) -> u64| -> Vec<char> {
+        let length = next(12);
+        (0..length).map(|_| (b'a' + next(4) as u8) as char).collect()
+    };
+
+    (0..count).map(|_| (text(&mut next), text(&mut next))).collect()
+}
+
+/// Checks that the script walks both sides from start to end without gaps, and that every
+/// operation agrees with the ranges and items it covers.
+fn assert_valid(name: &str, old: &[char], new: &[char], edits: &[Edit]) {
+    let (mut position_old, mut position_new) = (0, 0);
+
+    for (index, edit) in edits.iter().enumerate() {
+        assert_eq!((edit.old.start, edit.new.start), (position_old, position_new), "{} {:?} {:?}", name, old, new);
+        assert!(!edit.old.is_empty() || !edit.new.is_empty(), "{} {:?}", name, edit);
+
+        match edit.operation {
+            Operation::Equal => assert_eq!(old[edit.old.clone()], new[edit.new.clone()], "{} {:?}", name, edit),
+            Operation::Insert => assert!(edit.old.is_empty() && !edit.new.is_empty(), "{} {:?}", name, edit),
+            Operation::Delete => assert!(!edit.old.is_empty() && edit.new.is_empty(), "{} {:?}", name, edit),
+            Operation::Replace => assert!(!edit.old.is_empty() && !edit.new.is_empty(), "{} {:?}", name, edit),
+        }
+        if index > 0 {
+            assert!(edits[index - 1].operation != edit.operation, "{} {:?}", name, edits);
+        }
+
+        position_old = edit.old.end;
+        position_new = edit.new.end;
+    }
+
+    assert_eq!((position_old, position_new), (old.len(), new.len()), "{} {:?} {:?}", name, old, new);
+}
+
+fn matched(edits: &[Edit]) -> usize {
+    edits.iter().filter(|edit| edit.is_equal()).map(|edit| edit.old.len()).sum()
+}
+
+fn longest_common_subsequence(old: &[char], new: &[char]) -> usize {
+    let mut table = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for (row, first) in old.iter().enumerate() {
+        for (column, second) in new.iter().enumerate() {
+            table[row + 1][column + 1] = if first == second {
+                table[row][column] + 1
+            } else {
+                table[row][column + 1].max(table[row + 1][column])
+            };
+        }
+    }
+    table[old.len()][new.len()]
+}
+
+#[test]
+fn scripts_cover_both_sides() {
+    for (old, new) in samples(2000) {
+        for (name, diff) in ALGORITHMS {
+            assert_valid(name, &old, &new, &diff(&old, &new));
+        }
+    }
+}
+
+#[test]
+fn myers_is_optimal() {
+    for (old, new) in samples(2000) {
+        assert_eq!(matched(&myers(&old, &new)), longest_common_subsequence(&old, &new), "{:?} {:?}", old, new);
+    }
+}
+
+#[test]
+fn empty_inputs() {
+    let text = chars("abc");
+
+    for (name, diff) in ALGORITHMS {
+        assert_eq!(diff(&[], &[]), [], "{}", name);
+        assert_eq!(diff(&[], &text), [Edit::new(Operation::Insert, 0..0, 0..3)], "{}", name);
+        assert_eq!(diff(&text, &[]), [Edit::new(Operation::Delete, 0..3, 0..0)], "{}", name);
+        assert_eq!(diff(&text, &text), [Edit::new(Operation::Equal, 0..3, 0..3)], "{}", name);
+    }
+}
+
+const FROBNITZ: &str = "#include <stdio.h>
+
+// Frobs foo heartily
+int frobnitz(int foo)
+{
+    int i;
+    for(i = 0; i < 10; i++)
+    {
+        printf(\"Your answer is: \");
+        printf(\"%d\\n\", foo);
+    }
+}
+
+int fact(int n)
+{
+    if(n > 1)
+    {
+        return fact(n-1) * n;
+    }
+    return 1;
+}
+
+int main(int argc, char **argv)
+{
+    frobnitz(fact(10));
+}";
+
+const FIB: &str = "#include <stdio.h>
+
+int fib(int n)
+{
+    if(n > 2)
+    {
+        return fib(n-1) + fib(n-2);
+    }
+    return 1;
+}
+
+// Frobs foo heartily
+int frobnitz(int foo)
+{
+    int i;
+    for(i = 0; i < 10; i++)
+    {
+        printf(\"%d\\n\", foo);
+    }
+}
+
+int main(int argc, char **argv)
+{
+    frobnitz(fib(10));
+}";
+
+/// Whether the script pairs `old[from]` with `new[to]`.
+fn pairs(edits: &[Edit], from: usize, to: usize) -> bool {
+    edits.iter().any(|edit| {
+        edit.is_equal() && edit.old.contains(&from) && edit.new.contains(&to) && from - edit.old.start == to - edit.new.start
+    })
+}
+
+#[test]
+fn patience_anchors_on_unique_lines() {
+    // Myers lines the braces of the new `fib` up with those of `frobnitz`; the unique
+    // `int frobnitz(int foo)` line keeps that function whole and `fib` one insertion.
+    let old: Vec<&str> = FROBNITZ.lines().collect();
+    let new: Vec<&str> = FIB.lines().collect();
+
+    let edits = patience(&old, &new);
+    assert_eq!(edits[..3], [
+        Edit::new(Operation::Equal, 0..2, 0..2),
+        Edit::new(Operation::Insert, 2..2, 2..11),
+        Edit::new(Operation::Equal, 2..8, 11..17),
+    ]);
+    assert_eq!(histogram(&old, &new), edits);
+
+    let edits = myers(&old, &new);
+    assert!(!pairs(&edits, 3, 12));
+    assert!(pairs(&edits, 4, 3));
+}
@@ -0,0 +1,26 @@
+use matchete::{
+    geo::{GeoError, Geohash},
+    Resemblance, Resembler,
+};
+
+#[test]
+fn short_hashes() {
+    let mut geohash = Geohash::new(6);
+    let resemblance = |geohash: &mut Geohash, query: &str, candidate: &str| {
+        geohash.resemblance(&query.to_string(), &candidate.to_string())
+    };
+
+    assert_eq!(resemblance(&mut geohash, "u4pr", "u4pr").unwrap(), Resemblance::Perfect);
+    assert_eq!(resemblance(&mut geohash, "u4pr", "U4PR").unwrap(), Resemblance::Perfect);
+    assert_eq!(resemblance(&mut geohash, "u4pr", "u4px").unwrap(), Resemblance::Partial(0.75));
+    assert_eq!(resemblance(&mut geohash, "u4pruy", "u4pruz").unwrap().to_f64(), 5.0 / 6.0);
+}
+
+#[test]
+fn invalid_characters() {
+    let mut geohash = Geohash::default();
+    for hash in ["u4pa", "u4pi", "u4pl", "u4po", "u4p!"] {
+        let result = geohash.resemblance(&hash.to_string(), &"u4pr".to_string());
+        assert!(matches!(result, Err(GeoError::Geohash(_))), "{}", hash);
+    }
+}
@@ -0,0 +1,25 @@
+use matchete::string::utils::glob;
+
+#[test]
+fn wildcards_and_classes() {
+    assert!(glob("src/**/*.rs", "src/prelude/string/utils.rs"));
+    assert!(glob("src/**/*.rs", "src/lib.rs"));
+    assert!(glob("**/utils.rs", "utils.rs"));
+    assert!(!glob("src/*.rs", "src/prelude/lib.rs"));
+    assert!(glob("file?.txt", "file1.txt"));
+    assert!(!glob("file?.txt", "file/.txt"));
+    assert!(glob("[a-c]*.md", "beta.md"));
+    assert!(!glob("[!a-c]*.md", "beta.md"));
+    assert!(glob("[]]x", "]x"));
+    assert!(glob("[unclosed", "[unclosed"));
+}
+
+#[test]
+fn pathological_pattern_is_fast() {
+    let pattern = format!("{}b", "*a".repeat(10));
+    let text = "a".repeat(40);
+
+    assert!(!glob(&pattern, &text));
+    assert!(glob(&pattern, &format!("{}b", text)));
+    assert!(!glob(&format!("{}b", "**a".repeat(10)), &text));
+}
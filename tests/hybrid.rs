@@ -0,0 +1,72 @@
+use matchete::{
+    string::{Alphanumeric, Jaro, MongeElkan, Words},
+    Resemblance, Resembler,
+};
+
+const QUERY: &str = "Intl Business Machines Corp";
+const CANDIDATE: &str = "International Business Machine Corporation";
+
+fn score<R: Resembler<String, String, ()>>(resembler: &mut R, query: &str, candidate: &str) -> f64 {
+    resembler.resemblance(&query.to_string(), &candidate.to_string()).unwrap().to_f64()
+}
+
+#[test]
+fn monge_elkan_on_abbreviated_names() {
+    let mut jaro = Jaro::default();
+    let hybrid = score(&mut MongeElkan::new(&mut jaro), QUERY, CANDIDATE);
+
+    assert!(hybrid > 0.85, "{}", hybrid);
+    assert!(hybrid > score(&mut Words::default(), QUERY, CANDIDATE) + 0.5);
+    assert!(hybrid > score(&mut MongeElkan::new(&mut jaro), QUERY, "Acme Retail Holdings Ltd") + 0.2);
+}
+
+#[test]
+fn monge_elkan_direction() {
+    let mut jaro = Jaro::default();
+    let covered = score(&mut MongeElkan::new(&mut jaro), "Business Machines", CANDIDATE);
+    let covering = score(&mut MongeElkan::new(&mut jaro), CANDIDATE, "Business Machines");
+    let symmetric = score(&mut MongeElkan::new(&mut jaro).symmetric(true), CANDIDATE, "Business Machines");
+
+    assert!(covered > covering);
+    assert!((symmetric - (covered + covering) / 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn alphanumeric_numbers_decide() {
+    let mut jaro = Jaro::default();
+
+    assert!(score(&mut jaro, "iPhone 13", "iPhone 14") > 0.9);
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro), "iPhone 13", "iPhone 14"), 0.5);
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro).veto(true), "iPhone 13", "iPhone 14"), 0.0);
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro).tolerance(2.0), "iPhone 13", "iPhone 14"), 0.75);
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro).weight(1.0), "iPhone 13", "Galaxy 13"), 1.0);
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro), "Apt 12B", "Apt 12 B"), 1.0);
+}
+
+#[test]
+fn alphanumeric_number_counts() {
+    let mut jaro = Jaro::default();
+
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro), "Route 1 2", "Route 1"), 0.75);
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro).veto(true), "Route 1 2", "Route 1"), 0.0);
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro), "12", "13"), 0.0);
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro), "007", "7"), 1.0);
+}
+
+#[test]
+fn alphanumeric_decimals() {
+    let mut jaro = Jaro::default();
+
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro), "v3.14", "v3.15"), 0.75);
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro).decimals(true), "v3.14", "v3.15"), 0.5);
+    assert_eq!(score(&mut Alphanumeric::new(&mut jaro).decimals(true).tolerance(0.5), "3.25", "3.5"), 0.5);
+}
+
+#[test]
+fn alphanumeric_without_numbers() {
+    let mut jaro = Jaro::default();
+    let plain = jaro.resemblance(&"martha".to_string(), &"marhta".to_string()).unwrap();
+
+    assert_eq!(Alphanumeric::new(&mut jaro).resemblance(&"martha".to_string(), &"marhta".to_string()), Ok(plain));
+    assert_eq!(Alphanumeric::new(&mut jaro).resemblance(&"".to_string(), &"".to_string()), Ok(Resemblance::Perfect));
+}
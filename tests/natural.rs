@@ -0,0 +1,38 @@
+use {
+    core::cmp::Ordering,
+    matchete::string::utils::{natural, segments, Segment},
+};
+
+#[test]
+fn numbers_compare_by_value() {
+    assert_eq!(natural("file2", "file10"), Ordering::Less);
+    assert_eq!(natural("file10", "file2"), Ordering::Greater);
+    assert_eq!(natural("v1.9", "v1.10"), Ordering::Less);
+    assert_eq!(natural("Apt 12B", "Apt 102A"), Ordering::Less);
+    assert_eq!(natural("99999999999999999999998", "99999999999999999999999"), Ordering::Less);
+}
+
+#[test]
+fn leading_zeros() {
+    // Equal values fall back to the text itself, so the order stays total.
+    assert_eq!(natural("file007", "file7"), Ordering::Less);
+    assert_eq!(natural("file007", "file8"), Ordering::Less);
+    assert_eq!(natural("file7", "file7"), Ordering::Equal);
+}
+
+#[test]
+fn sorting() {
+    let mut files = vec!["file10.txt", "file2.txt", "File1.txt", "file1.txt", "file", "2 files", "file1b.txt"];
+    files.sort_by(|first, second| natural(first, second));
+
+    assert_eq!(files, ["2 files", "File1.txt", "file", "file1.txt", "file1b.txt", "file2.txt", "file10.txt"]);
+}
+
+#[test]
+fn splitting() {
+    assert_eq!(segments("iPhone 13 Pro", false), [Segment::Text("iPhone"), Segment::Number("13"), Segment::Text("Pro")]);
+    assert_eq!(segments("v3.14", false), [Segment::Text("v"), Segment::Number("3"), Segment::Number("14")]);
+    assert_eq!(segments("v3.14", true), [Segment::Text("v"), Segment::Number("3.14")]);
+    assert_eq!(segments("3. Main", true), [Segment::Number("3"), Segment::Text(". Main")]);
+    assert_eq!(segments("", false), []);
+}
@@ -0,0 +1,43 @@
+use matchete::{
+    numeric::{Absolute, Gaussian, Logarithmic, Relative},
+    Resemblance, Resembler,
+};
+
+#[test]
+fn large_integers_stay_distinct() {
+    let (query, candidate) = (9_007_199_254_740_993u64, 9_007_199_254_740_992u64);
+
+    assert_eq!(query as f64, candidate as f64);
+    assert_ne!(Absolute::new(10.0).resemblance(&query, &candidate).unwrap(), Resemblance::Perfect);
+    assert_ne!(Relative::new(0.1).resemblance(&query, &candidate).unwrap(), Resemblance::Perfect);
+    assert_ne!(Gaussian::new(5.0).resemblance(&query, &candidate).unwrap(), Resemblance::Perfect);
+    assert_ne!(Logarithmic::new(2.0).resemblance(&query, &candidate).unwrap(), Resemblance::Perfect);
+    assert_eq!(Absolute::new(10.0).resemblance(&query, &query).unwrap(), Resemblance::Perfect);
+}
+
+#[test]
+fn nan_parameters_only_match_equal_values() {
+    assert_eq!(Absolute::new(f64::NAN).resemblance(&1.0, &2.0).unwrap(), Resemblance::Disparity);
+    assert_eq!(Relative::new(f64::NAN).resemblance(&1.0, &2.0).unwrap(), Resemblance::Disparity);
+    assert_eq!(Logarithmic::new(f64::NAN).resemblance(&1.0, &2.0).unwrap(), Resemblance::Disparity);
+    assert_eq!(Gaussian::new(f64::NAN).resemblance(&1.0, &2.0).unwrap(), Resemblance::Disparity);
+    assert_eq!(Gaussian::new(f64::NAN).resemblance(&1.0, &1.0).unwrap(), Resemblance::Perfect);
+}
+
+#[test]
+fn infinite_parameters_match_everything() {
+    let almost = Resemblance::Partial(1.0 - f64::EPSILON);
+    let (low, high) = (-f64::MAX, f64::MAX);
+
+    for mut resembler in [Absolute::new(f64::INFINITY), Absolute::new(f64::NEG_INFINITY)] {
+        assert_eq!(resembler.resemblance(&1.0, &2.0).unwrap(), almost);
+        assert_eq!(resembler.resemblance(&low, &high).unwrap(), almost);
+        assert_eq!(resembler.resemblance(&3, &3).unwrap(), Resemblance::Perfect);
+    }
+    assert_eq!(Relative::new(f64::INFINITY).resemblance(&low, &high).unwrap(), almost);
+    assert_eq!(Logarithmic::new(f64::INFINITY).resemblance(&1e-300, &1e300).unwrap(), almost);
+    assert_eq!(Gaussian::new(f64::INFINITY).resemblance(&low, &high).unwrap(), almost);
+    assert_eq!(Gaussian::new(f64::INFINITY).resemblance(&7u8, &7u8).unwrap(), Resemblance::Perfect);
+
+    assert!(Absolute::new(f64::INFINITY).resemblance(&1.0, &f64::INFINITY).is_err());
+}
@@ -0,0 +1,88 @@
+use matchete::{myers, Algorithm, Merge, Patch, PatchError, Region};
+
+fn chars(text: &str) -> Vec<char> {
+    text.chars().collect()
+}
+
+#[test]
+fn apply_and_invert() {
+    let (old, new) = (chars("the quick brown fox"), chars("the quack brown cat!"));
+    let patch = Patch::new(&old, &new, &myers(&old, &new));
+
+    assert_eq!(patch.apply(&old), Ok(new.clone()));
+    assert_eq!(patch.invert().apply(&new), Ok(old.clone()));
+    assert_eq!(patch.invert().invert(), patch);
+}
+
+#[test]
+fn empty_sides() {
+    let (empty, full) = (Vec::new(), chars("abc"));
+
+    assert_eq!(Patch::new(&empty, &full, &myers(&empty, &full)).apply(&empty), Ok(full.clone()));
+    assert_eq!(Patch::new(&full, &empty, &myers(&full, &empty)).apply(&full), Ok(empty.clone()));
+    assert_eq!(Patch::new(&empty, &empty, &myers(&empty, &empty)).apply(&empty), Ok(empty));
+}
+
+#[test]
+fn length_mismatch() {
+    let (old, new) = (chars("abcdef"), chars("abXdef"));
+    let patch = Patch::new(&old, &new, &myers(&old, &new));
+
+    assert_eq!(patch.apply(&chars("abcde")), Err(PatchError::Length { expected: 6, found: 5 }));
+    assert_eq!(patch.invert().apply(&old[..2]), Err(PatchError::Length { expected: 6, found: 2 }));
+}
+
+#[test]
+fn context_mismatch() {
+    let (old, new) = (chars("abcdef"), chars("abXdef"));
+    let patch = Patch::new(&old, &new, &myers(&old, &new));
+
+    assert_eq!(patch.apply(&chars("abcdeZ")), Err(PatchError::Mismatch { position: 5 }));
+    assert_eq!(patch.apply(&chars("abYdef")), Err(PatchError::Mismatch { position: 2 }));
+    assert_eq!(patch.invert().apply(&old), Err(PatchError::Mismatch { position: 2 }));
+}
+
+#[test]
+fn true_conflict() {
+    let base = chars("abcde");
+    let merge = Merge::new(&base, &chars("abXde"), &chars("abYde"), Algorithm::Myers);
+
+    assert!(!merge.is_clean());
+    assert_eq!(merge.resolved(), None);
+    assert_eq!(merge.regions, [
+        Region::Resolved(chars("ab")),
+        Region::Conflict { range: 2..3, base: chars("c"), ours: chars("X"), theirs: chars("Y") },
+        Region::Resolved(chars("de")),
+    ]);
+}
+
+#[test]
+fn insertions_touching_other_changes_conflict() {
+    let merge = Merge::new(&chars("ab"), &chars("aXb"), &chars("aYb"), Algorithm::Myers);
+    assert_eq!(merge.conflicts().count(), 1);
+
+    let merge = Merge::new(&chars("abc"), &chars("abc!"), &chars("abZ"), Algorithm::Myers);
+    assert_eq!(merge.conflicts().count(), 1);
+}
+
+#[test]
+fn adjacent_edits_from_both_sides() {
+    let base = chars("abcdef");
+
+    for algorithm in [Algorithm::Myers, Algorithm::Patience, Algorithm::Histogram] {
+        let merge = Merge::new(&base, &chars("abXdef"), &chars("abcYef"), algorithm);
+        assert_eq!(merge.resolved(), Some(chars("abXYef")));
+
+        let merge = Merge::new(&base, &chars("acdef"), &chars("abYdef"), algorithm);
+        assert_eq!(merge.resolved(), Some(chars("aYdef")));
+    }
+}
+
+#[test]
+fn one_sided_and_identical_changes() {
+    let (base, changed) = (chars("abcdef"), chars("aXcdeY"));
+
+    assert_eq!(Merge::new(&base, &changed, &base, Algorithm::Myers).resolved(), Some(changed.clone()));
+    assert_eq!(Merge::new(&base, &base, &changed, Algorithm::Myers).resolved(), Some(changed.clone()));
+    assert_eq!(Merge::new(&base, &changed, &changed, Algorithm::Myers).resolved(), Some(changed));
+}
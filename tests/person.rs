@@ -0,0 +1,36 @@
+use matchete::{
+    person::{Name, Person},
+    Resembler,
+};
+
+fn score(query: &str, candidate: &str) -> f64 {
+    Person::new().resemblance(&query.to_string(), &candidate.to_string()).unwrap().to_f64()
+}
+
+#[test]
+fn parse_orders() {
+    assert_eq!(Name::parse("John Smith"), Name::new("John", &[], "Smith"));
+    assert_eq!(Name::parse("Smith, John Paul"), Name::new("John", &["Paul"], "Smith"));
+    assert_eq!(Name::parse("Ludwig van Beethoven"), Name::new("Ludwig", &[], "van Beethoven"));
+}
+
+#[test]
+fn parse_trailing_suffixes() {
+    assert_eq!(Name::parse("John Smith, Jr."), Name::new("John", &[], "Smith"));
+    assert_eq!(Name::parse("Dr. John Q. Smith, PhD"), Name::new("John", &["Q"], "Smith"));
+    assert_eq!(Name::parse("Smith, John, Jr."), Name::new("John", &[], "Smith"));
+}
+
+#[test]
+fn reordered_names() {
+    assert!(score("John Smith", "Smith John") > 0.9);
+    assert!(score("Li Wei", "Wei Li") > 0.9);
+    assert!(score("Jon Smith", "Smith Jonathan") > 0.85);
+}
+
+#[test]
+fn unrelated_names() {
+    assert!(score("John Smith", "Mike Jones") < 0.3);
+    assert!(score("David Miller", "Sarah Davis") < 0.5);
+    assert!(score("Anna Lee", "Leo Hanna") < 0.5);
+}
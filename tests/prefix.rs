@@ -0,0 +1,102 @@
+use matchete::{Completion, Trie};
+
+fn sample() -> Trie {
+    Trie::new()
+        .entry("New York", 5.0)
+        .entry("Newark", 3.0)
+        .entry("New Orleans", 4.0)
+        .entry("Newcastle", 1.0)
+        .entry("Boston", 2.0)
+}
+
+fn texts(completions: &[Completion]) -> Vec<&str> {
+    completions.iter().map(|completion| completion.text.as_str()).collect()
+}
+
+#[test]
+fn ranked_by_weight() {
+    let completions = sample().complete("new", 10);
+
+    assert_eq!(texts(&completions), ["New York", "New Orleans", "Newark", "Newcastle"]);
+    assert!(completions.iter().all(|completion| completion.distance == 0));
+    assert_eq!(completions[0].weight, 5.0);
+}
+
+#[test]
+fn reinserting_updates_the_weight() {
+    let trie = sample().entry("Newcastle", 9.0);
+
+    assert_eq!(trie.len(), 5);
+    assert_eq!(texts(&trie.complete("new", 1)), ["Newcastle"]);
+}
+
+#[test]
+fn limit() {
+    let trie = sample();
+
+    assert_eq!(texts(&trie.complete("new", 2)), ["New York", "New Orleans"]);
+    assert!(trie.complete("new", 0).is_empty());
+    assert!(trie.fuzzy("nwe", 1, 0).is_empty());
+    assert_eq!(trie.fuzzy("bostn", 2, 1).len(), 1);
+}
+
+#[test]
+fn empty_prefix() {
+    let trie = sample();
+
+    assert_eq!(texts(&trie.complete("", 3)), ["New York", "New Orleans", "Newark"]);
+    assert_eq!(trie.complete("  ", 10).len(), 5);
+    assert_eq!(texts(&trie.fuzzy("", 1, 1)), ["New York"]);
+    assert!(Trie::new().complete("", 10).is_empty());
+}
+
+#[test]
+fn missing_prefix() {
+    let trie = sample();
+
+    assert!(trie.complete("newz", 10).is_empty());
+    assert!(trie.complete("chicago", 10).is_empty());
+}
+
+#[test]
+fn typo_bounds() {
+    let trie = sample();
+
+    assert!(trie.fuzzy("nwe", 0, 10).is_empty());
+    assert_eq!(trie.fuzzy("new", 0, 10), trie.complete("new", 10));
+
+    let one = trie.fuzzy("bostn", 1, 10);
+    assert_eq!(texts(&one), ["Boston"]);
+    assert_eq!(one[0].distance, 1);
+
+    assert!(trie.fuzzy("bxstxn", 1, 10).is_empty());
+    assert_eq!(trie.fuzzy("bxstxn", 2, 10)[0].distance, 2);
+}
+
+#[test]
+fn fewer_typos_first() {
+    let trie = Trie::new().entry("cart", 1.0).entry("card", 10.0).entry("cat", 5.0);
+    let completions = trie.fuzzy("cart", 1, 10);
+
+    assert_eq!(completions[0], Completion { text: "cart".to_string(), weight: 1.0, distance: 0 });
+    assert_eq!(texts(&completions[1..]), ["card", "cat"]);
+    assert!(completions[1..].iter().all(|completion| completion.distance == 1));
+}
+
+#[test]
+fn non_ascii_keys() {
+    let trie = Trie::new()
+        .entry("Zürich", 3.0)
+        .entry("Zug", 1.0)
+        .entry("東京", 2.0)
+        .entry("東大阪", 1.0);
+
+    assert_eq!(texts(&trie.complete("zü", 10)), ["Zürich"]);
+    assert_eq!(texts(&trie.complete("ZÜ", 10)), ["Zürich"]);
+    assert_eq!(texts(&trie.complete("東", 10)), ["東京", "東大阪"]);
+
+    let fuzzy = trie.fuzzy("zurich", 1, 10);
+    assert_eq!(texts(&fuzzy), ["Zürich"]);
+    assert_eq!(fuzzy[0].distance, 1);
+    assert_eq!(texts(&trie.fuzzy("西京", 1, 10)), ["東京"]);
+}
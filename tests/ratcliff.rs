@@ -0,0 +1,44 @@
+use matchete::{string::Ratcliff, Resembler};
+
+/// Ratios computed with Python's `difflib.SequenceMatcher(None, a, b).ratio()`.
+const CASES: &[(&str, &str, f64)] = &[
+    ("abcd", "bcde", 0.75),
+    ("GESTALT PATTERN MATCHING", "GESTALT PRACTICE", 0.6),
+    ("kitten", "sitting", 0.6153846153846154),
+    ("", "abc", 0.0),
+    ("private Thread currentThread;", "private volatile Thread currentThread;", 0.8656716417910447),
+    ("WIKIMEDIA", "WIKIMANIA", 0.7777777777777778),
+    ("the quick brown fox", "the quick brown dog", 0.8947368421052632),
+];
+
+fn ratio(ratcliff: &mut Ratcliff, query: &str, candidate: &str) -> f64 {
+    ratcliff.resemblance(&query.to_string(), &candidate.to_string()).unwrap().to_f64()
+}
+
+#[test]
+fn matches_difflib() {
+    let mut ratcliff = Ratcliff::new();
+    for &(query, candidate, expected) in CASES {
+        let found = ratio(&mut ratcliff, query, candidate);
+        assert!((found - expected).abs() < 1e-12, "{:?} against {:?}: {} != {}", query, candidate, found, expected);
+    }
+}
+
+#[test]
+fn autojunk_on_long_candidates() {
+    // 252 characters, so every character occurring in more than 1% of them is junk.
+    let query = "lorem ipsum dolor sit amet ".repeat(9);
+    let candidate = "lorem ipsum dolour sit amet ".repeat(9);
+    assert_eq!(candidate.chars().count(), 252);
+
+    assert!((ratio(&mut Ratcliff::new(), &query, &candidate) - 0.06464646464646465).abs() < 1e-12);
+    assert!((ratio(&mut Ratcliff::new().autojunk(false), &query, &candidate) - 0.9818181818181818).abs() < 1e-12);
+}
+
+#[test]
+fn below_the_autojunk_length() {
+    let query = format!("{}{}the end", "ab".repeat(60), "x".repeat(30));
+    let candidate = format!("{}{}the end!", "ba".repeat(60), "y".repeat(30));
+
+    assert!((ratio(&mut Ratcliff::new(), &query, &candidate) - 0.8).abs() < 1e-12);
+}
@@ -0,0 +1,62 @@
+use matchete::{Inline, SideBySide, TextDiff, Unified};
+
+const OLD: &str = include_str!("golden/old.txt");
+const NEW: &str = include_str!("golden/new.txt");
+
+fn golden(name: &str, rendered: &str) {
+    let path = format!("{}/tests/golden/{}", env!("CARGO_MANIFEST_DIR"), name);
+    if std::env::var_os("MATCHETE_BLESS").is_some() {
+        std::fs::write(&path, rendered).unwrap();
+    }
+    let expected = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(rendered, expected, "rendered output differs from {}", name);
+}
+
+#[test]
+fn unified() {
+    let diff = TextDiff::lines(OLD, NEW);
+    golden("unified.diff", &Unified::new().labels("a/old.txt", "b/new.txt").render(&diff));
+}
+
+#[test]
+fn unified_context() {
+    let diff = TextDiff::lines(OLD, NEW);
+    golden("unified_context.diff", &Unified::new().context(1).render(&diff));
+}
+
+#[test]
+fn unified_missing_newline() {
+    let diff = TextDiff::lines("alpha\nbeta\n", "alpha\nbeta");
+    golden("unified_newline.diff", &Unified::new().render(&diff));
+}
+
+#[test]
+fn unified_identical() {
+    let diff = TextDiff::lines(OLD, OLD);
+    assert_eq!(Unified::new().render(&diff), "");
+}
+
+#[test]
+fn side_by_side() {
+    let diff = TextDiff::lines(OLD, NEW);
+    golden("side_by_side.txt", &SideBySide::new().width(24).render(&diff));
+}
+
+#[test]
+fn inline() {
+    let diff = TextDiff::words("the quick brown fox jumps over the lazy dog", "the quick red fox leaps over the dog!");
+    golden("inline.txt", &Inline::new().render(&diff));
+}
+
+#[test]
+fn inline_markers() {
+    let diff = TextDiff::chars("kitten", "sitting");
+    golden("inline_markers.txt", &Inline::new().markers(("<del>", "</del>"), ("<ins>", "</ins>")).render(&diff));
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn unified_ansi() {
+    let diff = TextDiff::lines(OLD, NEW);
+    golden("unified_ansi.diff", &Unified::new().context(1).color(true).render(&diff));
+}
@@ -0,0 +1,17 @@
+use matchete::sequence::assignment;
+
+#[test]
+fn assignment_with_nan_terminates() {
+    let (total, chosen) = assignment(&[vec![f64::NAN, f64::NAN], vec![0.5, 0.5]], 2);
+
+    assert_eq!(total, 0.5);
+    assert_eq!(chosen.iter().filter(|column| column.is_some()).count(), 2);
+}
+
+#[test]
+fn assignment_picks_the_heaviest_pairing() {
+    let (total, chosen) = assignment(&[vec![0.9, 0.8], vec![0.85, 0.1]], 2);
+
+    assert!((total - 1.65).abs() < 1e-9);
+    assert_eq!(chosen, vec![Some(1), Some(0)]);
+}
@@ -0,0 +1,65 @@
+use matchete::{
+    set::{Dice, Jaccard, Ochiai, Simpson, Tversky},
+    Resemblance, Resembler,
+};
+
+const QUERY: [&str; 3] = ["a", "a", "b"];
+const CANDIDATE: [&str; 4] = ["a", "b", "b", "c"];
+
+fn assert_close(found: f64, expected: f64) {
+    assert!((found - expected).abs() < 1e-12, "{} != {}", found, expected);
+}
+
+#[test]
+fn sets_ignore_repeats() {
+    assert_close(Jaccard::new(false).compute_resemblance(QUERY.to_vec(), CANDIDATE.to_vec()), 2.0 / 3.0);
+    assert_close(Dice::new(false).compute_resemblance(QUERY.to_vec(), CANDIDATE[..3].to_vec()), 1.0);
+    assert_close(Dice::new(false).compute_resemblance(QUERY.to_vec(), CANDIDATE.to_vec()), 0.8);
+    assert_close(Simpson::new(false).compute_resemblance(QUERY.to_vec(), CANDIDATE.to_vec()), 1.0);
+    assert_close(Ochiai::new(false).compute_resemblance(QUERY.to_vec(), CANDIDATE.to_vec()), 2.0 / 6f64.sqrt());
+    assert_close(Tversky::default().compute_resemblance(QUERY.to_vec(), CANDIDATE.to_vec()), 0.8);
+}
+
+#[test]
+fn multisets_count_repeats() {
+    // The query holds a twice and b once, the candidate a once, b twice and c once: two shared.
+    let (query, candidate) = (QUERY.to_vec(), CANDIDATE.to_vec());
+
+    assert_close(Jaccard::new(true).compute_resemblance(&query, &candidate), 2.0 / 5.0);
+    assert_close(Dice::new(true).compute_resemblance(&query, &candidate), 4.0 / 7.0);
+    assert_close(Simpson::new(true).compute_resemblance(&query, &candidate), 2.0 / 3.0);
+    assert_close(Ochiai::new(true).compute_resemblance(&query, &candidate), 4.0 / 30f64.sqrt());
+    assert_close(Tversky::new(0.5, 0.5, true).compute_resemblance(&query, &candidate), 4.0 / 7.0);
+}
+
+#[test]
+fn tversky_weights_each_side() {
+    let (query, candidate) = (QUERY.to_vec(), CANDIDATE.to_vec());
+
+    assert_close(Tversky::new(1.0, 0.0, false).compute_resemblance(&query, &candidate), 1.0);
+    assert_close(Tversky::new(0.0, 1.0, false).compute_resemblance(&query, &candidate), 2.0 / 3.0);
+    assert_close(Tversky::new(1.0, 0.0, true).compute_resemblance(&query, &candidate), 2.0 / 3.0);
+    assert_close(Tversky::new(0.0, 1.0, true).compute_resemblance(&query, &candidate), 0.5);
+    assert_close(Tversky::new(1.0, 1.0, true).compute_resemblance(&query, &candidate), 0.4);
+    assert_close(Tversky::new(0.0, 0.0, false).compute_resemblance(["x"], ["y"]), 0.0);
+}
+
+#[test]
+fn empty_collections() {
+    let empty: Vec<u32> = Vec::new();
+
+    assert_eq!(Jaccard::new(true).resemblance(&empty, &empty), Ok(Resemblance::Perfect));
+    assert_eq!(Dice::default().resemblance(&empty, &vec![1]), Ok(Resemblance::Disparity));
+    assert_eq!(Simpson::default().resemblance(&vec![1], &empty), Ok(Resemblance::Disparity));
+    assert_eq!(Ochiai::default().resemblance(&empty, &empty), Ok(Resemblance::Perfect));
+    assert_eq!(Tversky::default().resemblance(&empty, &empty), Ok(Resemblance::Perfect));
+}
+
+#[test]
+fn resemblers_over_any_collection() {
+    let tags = |items: &[&str]| items.iter().map(|tag| tag.to_string()).collect::<Vec<String>>();
+
+    assert_eq!(Jaccard::default().resemblance(&tags(&["rust", "cli"]), &tags(&["cli", "rust"])), Ok(Resemblance::Perfect));
+    assert_eq!(Simpson::default().resemblance(&[1, 2][..], &[1, 2, 3, 4][..]), Ok(Resemblance::Perfect));
+    assert_eq!(Dice::default().resemblance(&[1, 2][..], &[2, 3][..]), Ok(Resemblance::Partial(0.5)));
+}
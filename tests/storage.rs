@@ -0,0 +1,74 @@
+use matchete::{Index, IndexError, Mapped, View};
+
+fn sample() -> Index {
+    Index::new()
+        .size(3)
+        .entry("New York", 2.0)
+        .entry("Newark", 1.0)
+        .entry("York", 0.5)
+        .metadata("source", "cities")
+}
+
+#[test]
+fn round_trip() {
+    let index = sample();
+    let bytes = index.encode().unwrap();
+    let view = View::parse(&bytes).unwrap();
+
+    assert_eq!(view.size(), 3);
+    assert_eq!(view.len(), 3);
+    assert_eq!(view.text(1), Some("Newark"));
+    assert_eq!(view.weight(0), Some(2.0));
+    assert_eq!(view.get_metadata("source"), Some("cities"));
+    assert_eq!(view.candidates("york"), index.candidates("york"));
+    assert_eq!(view.to_index(), index);
+    assert_eq!(Index::decode(&bytes).unwrap(), index);
+}
+
+#[test]
+fn out_of_range_entry() {
+    let bytes = sample().encode().unwrap();
+    let view = View::parse(&bytes).unwrap();
+
+    assert_eq!(view.text(3), None);
+    assert_eq!(view.normalized(3), None);
+    assert_eq!(view.weight(usize::MAX), None);
+    assert!(view.grams(3).is_none());
+}
+
+#[test]
+fn zero_gram_size() {
+    let mut bytes = sample().encode().unwrap();
+    bytes[12..16].copy_from_slice(&0u32.to_le_bytes());
+
+    assert!(matches!(View::parse(&bytes), Err(IndexError::Corrupt("gram size"))));
+}
+
+#[test]
+fn trusted_open_skips_the_checksum() {
+    let path = std::env::temp_dir().join(format!("matchete-trusted-{}.idx", std::process::id()));
+    let mut bytes = sample().encode().unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] = b'x';
+    std::fs::write(&path, &bytes).unwrap();
+
+    let checked = Mapped::open(&path);
+    let trusted = Mapped::open_trusted(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(matches!(checked, Err(IndexError::Checksum { .. })));
+    assert_eq!(trusted.unwrap().view().len(), 3);
+}
+
+#[test]
+fn trusted_open_still_validates_the_layout() {
+    let path = std::env::temp_dir().join(format!("matchete-bounds-{}.idx", std::process::id()));
+    let mut bytes = sample().encode().unwrap();
+    bytes[12..16].copy_from_slice(&0u32.to_le_bytes());
+    std::fs::write(&path, &bytes).unwrap();
+
+    let trusted = Mapped::open_trusted(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(matches!(trusted, Err(IndexError::Corrupt("gram size"))));
+}
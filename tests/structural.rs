@@ -0,0 +1,166 @@
+use matchete::{
+    string::{
+        utils::{Span, Uniform},
+        Contains, Global, Local, Sequential, Subsequence, Substring,
+    },
+    Resemblance, Resembler,
+};
+
+fn score<R: Resembler<String, String, ()>>(resembler: &mut R, query: &str, candidate: &str) -> f64 {
+    resembler.resemblance(&query.to_string(), &candidate.to_string()).unwrap().to_f64()
+}
+
+#[test]
+fn local_finds_a_misspelled_query() {
+    let (query, line) = ("recieve payment", "Invoice 42: please receive payment by Friday");
+    let span = Local::new().locate(query, line).unwrap();
+
+    assert_eq!(&line[span.candidate.clone()], "receive payment");
+    assert_eq!(span, Span { query: 0..15, candidate: 19..34, score: 24.0 });
+    assert_eq!(score(&mut Contains, query, line), 0.0);
+    assert_eq!(score(&mut Local::new(), query, line), 0.8);
+}
+
+#[test]
+fn local_affine_gaps() {
+    let (query, candidate) = ("abcdefgh", "xxabcdXXXXXXefghyy");
+
+    // One gap of six: opening costs 2 and each further character 1, against 16 for the matches.
+    let bridged = Local::new().locate(query, candidate).unwrap();
+    assert_eq!(bridged, Span { query: 0..8, candidate: 2..16, score: 9.0 });
+
+    let cheap = Local::new().gap(2.0, 0.5).locate(query, candidate).unwrap();
+    assert_eq!(cheap.candidate, 2..16);
+    assert_eq!(cheap.score, 11.5);
+
+    let linear = Local::new().gap(3.0, 3.0).locate(query, candidate).unwrap();
+    assert_eq!(linear, Span { query: 0..4, candidate: 2..6, score: 8.0 });
+}
+
+#[test]
+fn local_spans_are_byte_ranges() {
+    let candidate = "un café noir";
+    let span = Local::new().locate("CAFÉ", candidate).unwrap();
+
+    assert_eq!(span.query, 0..5);
+    assert_eq!(&candidate[span.candidate], "café");
+}
+
+#[test]
+fn local_without_alignment() {
+    assert_eq!(Local::new().locate("xyz", "abc"), None);
+    assert_eq!(Local::new().locate("", "abc"), None);
+    assert_eq!(Local::new().resemblance(&"xyz".to_string(), &"abc".to_string()), Ok(Resemblance::Disparity));
+    assert_eq!(Local::new().resemblance(&"abc".to_string(), &"abc".to_string()), Ok(Resemblance::Perfect));
+}
+
+/// Deterministic ASCII strings over two letters, so alignments tie often.
+fn samples(count: usize) -> Vec<(String, String)> {
+    let mut state: u64 = 0x2545f4914f6cdd1d;
+    let mut next = move |bound: u64| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) % bound
+    };
+    let text = |next: &mut dyn FnMut(u64) -> u64| -> String {
+        let length = next(9);
+        (0..length).map(|_| if next(2) == 0 { 'a' } else { 'b' }).collect()
+    };
+
+    (0..count).map(|_| (text(&mut next), text(&mut next))).collect()
+}
+
+/// Score of the alignment the spans describe: their own scores less an affine cost for every
+/// run of unaligned characters on either side.
+fn replay(spans: &[Span], query: &str, candidate: &str, open: f64, extend: f64) -> f64 {
+    let gap = |length: usize| if length == 0 { 0.0 } else { open + (length - 1) as f64 * extend };
+    let (mut query_end, mut candidate_end) = (0, 0);
+    let mut total = 0.0;
+
+    for span in spans {
+        total += span.score - gap(span.query.start - query_end) - gap(span.candidate.start - candidate_end);
+        query_end = span.query.end;
+        candidate_end = span.candidate.end;
+    }
+
+    total - gap(query.len() - query_end) - gap(candidate.len() - candidate_end)
+}
+
+#[test]
+fn global_traceback_matches_its_score() {
+    let global = || Global::new().substitution(Uniform::new(0.7, 0.3)).gap(0.3, 0.1);
+
+    for (query, candidate) in samples(3000) {
+        let spans = global().spans(&query, &candidate);
+        let ideal = 0.7 * query.len().max(candidate.len()) as f64;
+        let expected = match global().resemblance(&query, &candidate).unwrap() {
+            Resemblance::Partial(score) => score * ideal,
+            _ => continue,
+        };
+
+        let replayed = replay(&spans, &query, &candidate, 0.3, 0.1);
+        assert!((replayed - expected).abs() < 1e-9, "{:?} {:?} {:?}", query, candidate, spans);
+    }
+}
+
+#[test]
+fn global_ties() {
+    // Either `a` of the query can pair with the candidate's; ties prefer aligning at the end.
+    let spans = Global::new().spans("aa", "a");
+    assert_eq!(spans, [Span { query: 1..2, candidate: 0..1, score: 1.0 }]);
+
+    let spans = Global::new().spans("abab", "ab");
+    assert_eq!(spans, [Span { query: 2..4, candidate: 0..2, score: 2.0 }]);
+}
+
+#[test]
+fn global_gaps() {
+    let spans = Global::new().spans("kitten", "sitting");
+    assert_eq!(spans, [Span { query: 0..6, candidate: 0..6, score: 2.0 }]);
+
+    let spans = Global::new().gap(1.0, 0.0).spans("abcdef", "abXXXXcdef");
+    assert_eq!(spans, [
+        Span { query: 0..2, candidate: 0..2, score: 2.0 },
+        Span { query: 2..6, candidate: 6..10, score: 4.0 },
+    ]);
+    assert_eq!(Global::new().spans("", "abc"), []);
+}
+
+#[test]
+fn subsequence() {
+    let spans = Subsequence.spans("AGGTAB", "GXTXAYB");
+
+    assert_eq!(spans.iter().map(|span| span.score).sum::<f64>(), 4.0);
+    assert_eq!(spans.iter().map(|span| &"AGGTAB"[span.query.clone()]).collect::<String>(), "GTAB");
+    assert_eq!(score(&mut Subsequence, "AGGTAB", "GXTXAYB"), 8.0 / 13.0);
+    assert_eq!(score(&mut Subsequence, "Abc", "aBC"), 1.0);
+    assert_eq!(score(&mut Subsequence, "abc", "xyz"), 0.0);
+}
+
+#[test]
+fn substring() {
+    let spans = Substring.spans("xabcdy", "zzabcdzz");
+    assert_eq!(spans, [Span { query: 1..5, candidate: 2..6, score: 4.0 }]);
+
+    // The earliest of equally long runs in the query wins.
+    let spans = Substring.spans("abXcd", "cdYab");
+    assert_eq!(spans, [Span { query: 0..2, candidate: 3..5, score: 2.0 }]);
+
+    assert_eq!(score(&mut Substring, "xabcdy", "zzabcdzz"), 8.0 / 14.0);
+    assert!(Substring.spans("abc", "xyz").is_empty());
+    assert_eq!(score(&mut Substring, "", ""), 1.0);
+}
+
+#[test]
+fn sequential_counts_repeated_grams() {
+    // Bigrams `aa aa` against `aa`: one shared out of three, as a multiset Dice coefficient.
+    assert_eq!(score(&mut Sequential::default(), "aaa", "aa"), 2.0 / 3.0);
+    assert_eq!(score(&mut Sequential::default(), "abab", "ab"), 0.5);
+    assert_eq!(score(&mut Sequential::default(), "night", "nacht"), 0.25);
+}
+
+#[test]
+fn sequential_on_short_multibyte_text() {
+    assert_eq!(score(&mut Sequential::new(3), "日本", "日本語"), 0.0);
+    assert_eq!(score(&mut Sequential::new(3), "日本", "日本"), 1.0);
+    assert_eq!(score(&mut Sequential::new(3), "café", "cafe"), 0.5);
+}
@@ -0,0 +1,15 @@
+use matchete::temporal::{Date, Order, TemporalError};
+
+#[test]
+fn overflowing_fields_are_rejected() {
+    for text in ["07/259/1984", "263/03/1984", "1984-03-263", "1984-259", "March 263, 1984"] {
+        assert!(matches!(Date::parse(text, Order::DayFirst), Err(TemporalError::Unparsable(_))), "{}", text);
+    }
+}
+
+#[test]
+fn numeric_orders() {
+    assert_eq!(Date::parse("07/03/1984", Order::DayFirst).unwrap().to_string(), "1984-03-07");
+    assert_eq!(Date::parse("03/07/1984", Order::MonthFirst).unwrap().to_string(), "1984-03-07");
+    assert_eq!(Date::parse("19840307", Order::DayFirst).unwrap().to_string(), "1984-03-07");
+}
@@ -0,0 +1,16 @@
+use matchete::{Change, Location, TreeDiff, Value};
+
+#[test]
+fn removal_inside_shifted_element() {
+    let record = |fields: &[(&str, i64)]| Value::map(fields.iter().map(|&(key, value)| (key, Value::from(value))));
+    let old = Value::sequence([Value::from("x"), record(&[("a", 1), ("b", 2), ("c", 3)])]);
+    let new = Value::sequence([Value::from("y"), Value::from("z"), record(&[("a", 1), ("b", 2)])]);
+
+    let changes = TreeDiff::<()>::new().diff(&old, &new).unwrap();
+    let removed = changes.iter().find_map(|change| match change {
+        Change::Removed { location, value } if *value == Value::from(3) => Some(location.clone()),
+        _ => None,
+    });
+
+    assert_eq!(removed, Some(Location::root().index(1).key("c")));
+}
@@ -0,0 +1,118 @@
+use matchete::{
+    vector::{Angular, Dot, Euclidean, Flat, Hnsw, Metric, VectorError},
+    Resemblance, Resembler,
+};
+
+/// Deterministic points in the unit cube, from a linear congruential generator.
+fn points(count: usize, dimension: usize, seed: u64) -> Vec<Vec<f32>> {
+    let mut state = seed;
+    let mut next = move || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 40) as f32 / (1u64 << 24) as f32
+    };
+
+    (0..count).map(|_| (0..dimension).map(|_| next()).collect()).collect()
+}
+
+fn recall(metric: Metric) -> f64 {
+    let mut flat = Flat::new(metric);
+    let mut hnsw = Hnsw::new(metric).connections(8);
+    for point in points(400, 8, 7) {
+        flat.insert(point.clone()).unwrap();
+        hnsw.insert(point).unwrap();
+    }
+
+    let mut found = 0;
+    let mut expected = 0;
+    for query in points(25, 8, 11) {
+        let exact: Vec<usize> = flat.search(&query, 10).unwrap().iter().map(|neighbor| neighbor.id).collect();
+        let approximate = hnsw.search(&query, 10).unwrap();
+
+        assert_eq!(approximate.len(), 10);
+        assert!(approximate.windows(2).all(|pair| pair[0].distance <= pair[1].distance));
+        found += approximate.iter().filter(|neighbor| exact.contains(&neighbor.id)).count();
+        expected += exact.len();
+    }
+
+    found as f64 / expected as f64
+}
+
+#[test]
+fn hnsw_recall_matches_flat() {
+    assert!(recall(Metric::Euclidean) >= 0.95);
+    assert!(recall(Metric::Cosine) >= 0.95);
+}
+
+#[test]
+fn flat_is_exact() {
+    let mut flat = Flat::new(Metric::Euclidean);
+    for point in [vec![0.0, 0.0], vec![3.0, 4.0], vec![1.0, 1.0]] {
+        flat.insert(point).unwrap();
+    }
+
+    let neighbors = flat.search(&[0.9, 0.9], 2).unwrap();
+    assert_eq!(neighbors.iter().map(|neighbor| neighbor.id).collect::<Vec<_>>(), [2, 0]);
+    assert!(flat.search(&[0.0, 0.0], 0).unwrap().is_empty());
+}
+
+#[test]
+fn empty_indexes() {
+    assert!(Flat::new(Metric::Cosine).search(&[1.0], 3).unwrap().is_empty());
+    assert!(Hnsw::new(Metric::Cosine).search(&[1.0], 3).unwrap().is_empty());
+}
+
+#[test]
+fn dimension_mismatch() {
+    let mismatch = Err(VectorError::Dimension { query: 2, candidate: 3 });
+
+    assert_eq!(Angular.resemblance(&[1.0, 0.0][..], &[1.0, 0.0, 0.0][..]), mismatch);
+    assert_eq!(Euclidean::default().resemblance(&vec![1.0, 0.0], &vec![1.0, 0.0, 0.0]), mismatch);
+
+    let mut flat = Flat::new(Metric::Euclidean);
+    let mut hnsw = Hnsw::new(Metric::Euclidean);
+    flat.insert(vec![1.0, 0.0, 0.0]).unwrap();
+    hnsw.insert(vec![1.0, 0.0, 0.0]).unwrap();
+
+    assert_eq!(flat.insert(vec![1.0, 0.0]), Err(VectorError::Dimension { query: 3, candidate: 2 }));
+    assert_eq!(hnsw.insert(vec![1.0, 0.0]), Err(VectorError::Dimension { query: 3, candidate: 2 }));
+    assert!(matches!(flat.search(&[1.0, 0.0], 1), Err(VectorError::Dimension { query: 2, candidate: 3 })));
+    assert!(matches!(hnsw.search(&[1.0, 0.0], 1), Err(VectorError::Dimension { query: 2, candidate: 3 })));
+    assert_eq!(flat.len(), 1);
+    assert_eq!(hnsw.len(), 1);
+}
+
+#[test]
+fn empty_and_zero_vectors() {
+    assert_eq!(Dot.resemblance(&[][..], &[][..]), Err(VectorError::Empty));
+    assert_eq!(Flat::new(Metric::Cosine).insert(Vec::new()), Err(VectorError::Empty));
+    assert_eq!(Hnsw::new(Metric::Cosine).insert(Vec::new()), Err(VectorError::Empty));
+
+    assert_eq!(Angular.resemblance(&[0.0, 0.0][..], &[1.0, 0.0][..]), Ok(Resemblance::Disparity));
+    assert_eq!(Angular.resemblance(&[0.0, 0.0][..], &[0.0, 0.0][..]), Ok(Resemblance::Disparity));
+    assert_eq!(Euclidean::default().resemblance(&[0.0, 0.0][..], &[0.0, 0.0][..]), Ok(Resemblance::Perfect));
+}
+
+#[test]
+fn similarity_scores() {
+    assert_eq!(Angular.resemblance(&[1.0, 0.0][..], &[2.0, 0.0][..]), Ok(Resemblance::Perfect));
+    assert_eq!(Angular.resemblance(&[1.0, 0.0][..], &[-1.0, 0.0][..]), Ok(Resemblance::Disparity));
+    assert_eq!(Dot.resemblance(&[1.0, 0.0][..], &[0.0, 1.0][..]), Ok(Resemblance::Partial(0.5)));
+    assert_eq!(Euclidean::new(5.0).resemblance(&[0.0, 0.0][..], &[3.0, 4.0][..]), Ok(Resemblance::Partial(0.5)));
+}
+
+/// Unsized queries and candidates, reached through a trait object.
+fn best(resembler: &mut dyn Resembler<[f32], [f32], VectorError>, query: &[f32], candidates: &[&[f32]]) -> usize {
+    let scores: Vec<f64> = candidates.iter()
+        .map(|candidate| resembler.resemblance(query, candidate).unwrap().to_f64())
+        .collect();
+
+    (0..candidates.len()).max_by(|&first, &second| scores[first].total_cmp(&scores[second])).unwrap()
+}
+
+#[test]
+fn unsized_slices() {
+    let candidates: [&[f32]; 3] = [&[0.0, 1.0], &[1.0, 0.1], &[-1.0, 0.0]];
+
+    assert_eq!(best(&mut Angular, &[1.0, 0.0], &candidates), 1);
+    assert_eq!(best(&mut Euclidean::default(), &[-0.9, 0.0], &candidates), 2);
+}
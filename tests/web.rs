@@ -0,0 +1,53 @@
+use matchete::{
+    web::{registrable, Domain, Email, Url},
+    Resemblance, Resembler,
+};
+
+fn score<R: Resembler<String, String, ()>>(resembler: &mut R, query: &str, candidate: &str) -> Resemblance {
+    resembler.resemblance(&query.to_string(), &candidate.to_string()).unwrap()
+}
+
+#[test]
+fn url_ports() {
+    let mut url = Url::new();
+
+    assert_eq!(score(&mut url, "http://example.com:80/a", "example.com/a"), Resemblance::Perfect);
+    assert_eq!(score(&mut url, "example.com:8080/a", "example.com:9090/a"), Resemblance::Disparity);
+    assert_eq!(score(&mut url, "example.com:8080/a", "example.com/a"), Resemblance::Disparity);
+}
+
+#[test]
+fn url_fragments() {
+    assert_eq!(score(&mut Url::new(), "example.com/app#/users", "example.com/app#/settings"), Resemblance::Perfect);
+
+    let mut url = Url::new().fragment(true);
+    let routed = score(&mut url, "example.com/app#/users", "example.com/app#/settings");
+    assert!(routed != Resemblance::Perfect && routed.to_f64() > 0.9, "{:?}", routed);
+    assert_eq!(score(&mut url, "example.com/app#/users", "example.com/app/#/users"), Resemblance::Perfect);
+}
+
+#[test]
+fn ip_literals() {
+    assert_eq!(registrable("192.168.1.10"), "192.168.1.10");
+    assert_eq!(registrable("[2001:db8::1]"), "[2001:db8::1]");
+    assert_eq!(registrable("news.bbc.co.uk"), "bbc.co.uk");
+
+    let mut domain = Domain::new();
+    assert_eq!(score(&mut domain, "192.168.1.10", "10.0.1.10"), Resemblance::Disparity);
+    assert_eq!(score(&mut domain, "192.168.1.10", "192.168.1.10."), Resemblance::Perfect);
+    assert_eq!(score(&mut domain, "[::1]", "[::2]"), Resemblance::Disparity);
+
+    let mut url = Url::new();
+    assert_eq!(score(&mut url, "http://192.168.1.10/a", "http://10.0.1.10/a"), Resemblance::Disparity);
+    assert_eq!(score(&mut url, "http://[::1]:8080/a", "http://[::1]:8080/a/"), Resemblance::Perfect);
+}
+
+#[test]
+fn emails_without_a_domain() {
+    let mut email = Email::new();
+
+    assert_eq!(score(&mut email, "J.Doe+news@GoogleMail.com", "jdoe@gmail.com"), Resemblance::Perfect);
+    assert!(score(&mut email, "jdoe", "jdoe@gmail.com").to_f64() <= 0.7);
+    assert!(score(&mut email, "jdoe", "jdoe2").to_f64() <= 0.7);
+    assert!(score(&mut email, "jdoe@example.com", "jdoe2@example.com").to_f64() > 0.9);
+}
@@ -0,0 +1,48 @@
+use matchete::{
+    language::{Language, Lemmatizer, Porter2, Snowball, Unicode, Whitespace},
+    string::Words,
+    Resemblance, Resembler,
+};
+
+#[test]
+fn equality() {
+    assert!(Words::default() == Words::default());
+    assert!(Words::default().stemmer(Porter2) == Words::default().stemmer(Porter2));
+    assert!(Words::default().stop_words(["the"]) == Words::default().stop_words(["the"]));
+
+    assert!(Words::default() != Words::default().stemmer(Porter2));
+    assert!(Words::default() != Words::default().tokenizer(Unicode));
+    assert!(Words::default() != Words::default().ignore_case(false));
+    assert!(Words::default() != Words::default().min_word_len(3));
+    assert!(Words::default() != Words::default().stop_words(["the"]));
+}
+
+#[test]
+fn stemmers_that_agree_on_common_words() {
+    let lemmas = || Lemmatizer::new().lemma("went", "go").fallback(Porter2);
+
+    assert!(Words::default().stemmer(lemmas()) == Words::default().stemmer(lemmas()));
+    assert!(Words::default().stemmer(lemmas()) != Words::default().stemmer(lemmas().lemma("mice", "mouse")));
+    assert!(Words::default().stemmer(lemmas()) != Words::default().stemmer(Lemmatizer::new().lemma("went", "go")));
+    assert!(Words::default().stemmer(Snowball::new(Language::Swedish)) != Words::default().stemmer(Snowball::new(Language::Norwegian)));
+    assert!(Words::default().stemmer(Snowball::new(Language::English)) != Words::default().stemmer(Porter2));
+}
+
+#[test]
+fn separators_in_any_order() {
+    let tokenizer = |separators: Vec<char>| Words::default().tokenizer(Whitespace::new().separators(separators));
+
+    assert!(tokenizer(vec!['-', ',']) == tokenizer(vec![',', '-', ',']));
+    assert!(tokenizer(vec!['-']) != tokenizer(vec!['-', '/']));
+    assert!(tokenizer(vec![]) == Words::default());
+}
+
+#[test]
+fn repeated_words_count_once_each() {
+    // Short texts score by multiset Jaccard: one shared `new` out of two.
+    let mut words = Words::default();
+
+    assert_eq!(words.resemblance(&"new new".to_string(), &"new".to_string()), Ok(Resemblance::Partial(0.5)));
+    assert_eq!(words.resemblance(&"new new".to_string(), &"New NEW".to_string()), Ok(Resemblance::Perfect));
+    assert_eq!(words.resemblance(&"new york".to_string(), &"york york".to_string()), Ok(Resemblance::Partial(1.0 / 3.0)));
+}